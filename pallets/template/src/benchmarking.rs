@@ -20,6 +20,98 @@ mod benchmarks {
 		frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 	}
 
+	fn setup_election<T: Config>(start: u32, end: u32) -> ElectionId {
+		setup_election_with::<T>(start, end, VotingMethod::Plurality)
+	}
+
+	fn setup_election_with<T: Config>(start: u32, end: u32, method: VotingMethod) -> ElectionId {
+		let election_id = Pallet::<T>::next_election_id();
+		Pallet::<T>::create_election(
+			RawOrigin::Root.into(),
+			start.into(),
+			end.into(),
+			None,
+			ElectionSettings {
+				method,
+				max_score: 10,
+				seats: 1,
+				credits: 10_000,
+				weighting: VoteWeighting::OnePerAccount,
+				conviction: false,
+				eligibility: Eligibility::SelfRegistered,
+				tie_break: TieBreak::KeepAll,
+				majority: None,
+				quorum: None,
+			},
+			Default::default(),
+		)
+		.expect("election can be created");
+		election_id
+	}
+
+	fn setup_secret_election<T: Config>(start: u32, end: u32, reveal_end: u32) -> ElectionId {
+		let election_id = Pallet::<T>::next_election_id();
+		Pallet::<T>::create_election(
+			RawOrigin::Root.into(),
+			start.into(),
+			end.into(),
+			Some(reveal_end.into()),
+			Default::default(),
+			Default::default(),
+		)
+		.expect("election can be created");
+		election_id
+	}
+
+	fn setup_weighted_election<T: Config>(start: u32, end: u32) -> ElectionId {
+		let election_id = Pallet::<T>::next_election_id();
+		Pallet::<T>::create_election(
+			RawOrigin::Root.into(),
+			start.into(),
+			end.into(),
+			None,
+			ElectionSettings {
+				weighting: VoteWeighting::FreeBalance,
+				conviction: true,
+				..Default::default()
+			},
+			Default::default(),
+		)
+		.expect("election can be created");
+		election_id
+	}
+
+	fn funded_voter<T: Config>() -> T::AccountId {
+		let voter: T::AccountId = account("Alice", 1u32, 2u32);
+		T::Currency::make_free_balance_be(&voter, 1_000_000u32.into());
+		Pallet::<T>::register_voter(RawOrigin::Signed(voter.clone()).into())
+			.expect("voter can register");
+		voter
+	}
+
+	fn profile<T: Config>() -> (ProfileText, Option<ProfileText>, T::Hash) {
+		let name: ProfileText = vec![b'n'; 100].try_into().unwrap();
+		let party: ProfileText = vec![b'p'; 100].try_into().unwrap();
+		(name, Some(party), T::Hash::default())
+	}
+
+	/// Gives `candidate` just enough to reserve the candidacy bond.
+	fn fund_candidate<T: Config>(candidate: &T::AccountId) {
+		let balance = T::CandidacyBond::get() + T::Currency::minimum_balance();
+		T::Currency::make_free_balance_be(candidate, balance);
+	}
+
+	fn add_candidate<T: Config>(
+		origin: <T as frame_system::Config>::RuntimeOrigin,
+		election_id: ElectionId,
+	) {
+		let candidate = frame_system::ensure_signed(origin.clone()).expect("candidates sign");
+		fund_candidate::<T>(&candidate);
+		let (name, party, manifesto_hash) = profile::<T>();
+		Pallet::<T>::register_candidate(origin, election_id, name, party, manifesto_hash)
+			.expect("candidate can register");
+	}
+
 	// This will measure the execution time of `register_voter`.
 	#[benchmark]
 	fn add_voter() {
@@ -36,109 +128,558 @@ mod benchmarks {
 	fn register_candidate() {
 		let candidate: T::AccountId = account("Bob", 1u32, 2u32);
 		let candidate_origin = RawOrigin::Signed(candidate.clone());
+		let election_id = setup_election::<T>(2, 10);
+		let (name, party, manifesto_hash) = profile::<T>();
+		fund_candidate::<T>(&candidate);
 
 		#[extrinsic_call]
-		_(candidate_origin.clone());
+		_(candidate_origin.clone(), election_id, name, party, manifesto_hash);
 
-		assert_last_event::<T>(Event::RegisterCandidate.into());
+		assert_last_event::<T>(Event::RegisterCandidate { election_id }.into());
 	}
 
 	#[benchmark]
-	fn config_election() {
-		let (start, end): (u32, u32) = (2, 10);
-		let root_origin = RawOrigin::Root;
+	fn update_candidate_profile() {
+		let candidate: T::AccountId = account("Bob", 1u32, 2u32);
+		let election_id = setup_election::<T>(2, 10);
+		add_candidate::<T>(RawOrigin::Signed(candidate.clone()).into(), election_id);
+		let (name, party, manifesto_hash) = profile::<T>();
 
 		#[extrinsic_call]
-		_(root_origin, start.into(), end.into());
+		_(RawOrigin::Signed(candidate.clone()), election_id, name, party, manifesto_hash);
 
-		assert_last_event::<T>(Event::ElectionConfigured.into());
+		assert_last_event::<T>(Event::CandidateProfileUpdated { election_id, candidate }.into());
+	}
+
+	#[benchmark]
+	fn create_election() {
+		let (start, end, reveal_end): (u32, u32, u32) = (2, 10, 15);
+		let root_origin = RawOrigin::Root;
+		let election_id = Pallet::<T>::next_election_id();
+		let window = PhaseWindow { start: 0u32.into(), end: 1u32.into() };
+		let phases = ElectionPhases { nomination: Some(window), registration: Some(window) };
+
+		#[extrinsic_call]
+		_(
+			root_origin,
+			start.into(),
+			end.into(),
+			Some(reveal_end.into()),
+			Default::default(),
+			phases,
+		);
+
+		assert_last_event::<T>(Event::ElectionCreated { election_id }.into());
 	}
 
 	#[benchmark]
 	fn give_vote() {
+		let voter = funded_voter::<T>();
+		// A token-weighted ballot also locks the balance it counts
+		let election_id = setup_weighted_election::<T>(2, 10);
+		// Voting directly takes back a delegation to the end of the longest chain
+		let to = setup_delegation_chain::<T>(election_id);
+		Pallet::<T>::delegate(RawOrigin::Signed(voter.clone()).into(), election_id, to)
+			.expect("voter can delegate");
+		let candidate: T::AccountId = account("Candidate", 1u32, 1u32);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter), election_id, candidate);
+
+		assert_last_event::<T>(Event::VoteSuccess { election_id }.into());
+	}
+
+	#[benchmark]
+	fn give_ranked_vote() {
+		let voter: T::AccountId = account("Alice", 1u32, 2u32);
+		let election_id = setup_election_with::<T>(2, 10, VotingMethod::InstantRunoff);
+
+		let mut ranking: Vec<T::AccountId> = vec![];
+		for i in 0..MaxCandidates::get() {
+			let candidate: T::AccountId = account("Candidate", 1u32, i);
+			add_candidate::<T>(RawOrigin::Signed(candidate.clone()).into(), election_id);
+			ranking.push(candidate);
+		}
+		let ranking: CandidateList<T> = ranking.try_into().unwrap();
+
+		Pallet::<T>::register_voter(RawOrigin::Signed(voter.clone()).into())
+			.expect("voter can register");
+		frame_system::Pallet::<T>::set_block_number(2u32.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter), election_id, ranking);
+
+		assert_last_event::<T>(Event::RankedVoteSuccess { election_id }.into());
+	}
+
+	#[benchmark]
+	fn give_approval_vote() {
+		let voter: T::AccountId = account("Alice", 1u32, 2u32);
+		let election_id = setup_election_with::<T>(2, 10, VotingMethod::Approval);
+
+		let mut approved: Vec<T::AccountId> = vec![];
+		for i in 0..MaxCandidates::get() {
+			let candidate: T::AccountId = account("Candidate", 1u32, i);
+			add_candidate::<T>(RawOrigin::Signed(candidate.clone()).into(), election_id);
+			approved.push(candidate);
+		}
+		let approved: CandidateList<T> = approved.try_into().unwrap();
+
+		Pallet::<T>::register_voter(RawOrigin::Signed(voter.clone()).into())
+			.expect("voter can register");
+		frame_system::Pallet::<T>::set_block_number(2u32.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter), election_id, approved);
+
+		assert_last_event::<T>(Event::ApprovalVoteSuccess { election_id }.into());
+	}
+
+	#[benchmark]
+	fn give_score_vote() {
+		let voter: T::AccountId = account("Alice", 1u32, 2u32);
+		let election_id = setup_election_with::<T>(2, 10, VotingMethod::Star);
+
+		let mut scores: Vec<(T::AccountId, u32)> = vec![];
+		for i in 0..MaxCandidates::get() {
+			let candidate: T::AccountId = account("Candidate", 1u32, i);
+			add_candidate::<T>(RawOrigin::Signed(candidate.clone()).into(), election_id);
+			scores.push((candidate, i % 10));
+		}
+		let scores: ScoreList<T> = scores.try_into().unwrap();
+
+		Pallet::<T>::register_voter(RawOrigin::Signed(voter.clone()).into())
+			.expect("voter can register");
+		frame_system::Pallet::<T>::set_block_number(2u32.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter), election_id, scores);
+
+		assert_last_event::<T>(Event::ScoreVoteSuccess { election_id }.into());
+	}
+
+	#[benchmark]
+	fn give_votes() {
 		let voter: T::AccountId = account("Alice", 1u32, 2u32);
+		let election_id = setup_election_with::<T>(2, 10, VotingMethod::Quadratic);
+
+		let mut candidates: Vec<T::AccountId> = vec![];
+		for i in 0..MaxCandidates::get() {
+			let candidate: T::AccountId = account("Candidate", 1u32, i);
+			add_candidate::<T>(RawOrigin::Signed(candidate.clone()).into(), election_id);
+			candidates.push(candidate);
+		}
+
+		Pallet::<T>::register_voter(RawOrigin::Signed(voter.clone()).into())
+			.expect("voter can register");
+		frame_system::Pallet::<T>::set_block_number(2u32.into());
+
+		// Worst case: the voter's ballot already holds every other candidate
+		let last = candidates.pop().unwrap();
+		for candidate in candidates {
+			Pallet::<T>::give_votes(RawOrigin::Signed(voter.clone()).into(), election_id, candidate, 1)
+				.expect("voter can afford one vote");
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter), election_id, last.clone(), 1);
+
+		assert_last_event::<T>(
+			Event::QuadraticVoteSuccess { election_id, candidate: last, votes: 1 }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn commit_vote() {
+		let voter = funded_voter::<T>();
 		let candidate: T::AccountId = account("Bob", 2u32, 3u32);
-		let (start, end): (u32, u32) = (2, 10);
+		let election_id = Pallet::<T>::next_election_id();
+		Pallet::<T>::create_election(
+			RawOrigin::Root.into(),
+			2u32.into(),
+			10u32.into(),
+			Some(15u32.into()),
+			ElectionSettings { weighting: VoteWeighting::FreeBalance, ..Default::default() },
+			Default::default(),
+		)
+		.expect("election can be created");
+
+		add_candidate::<T>(RawOrigin::Signed(candidate.clone()).into(), election_id);
+		frame_system::Pallet::<T>::set_block_number(2u32.into());
+		let commitment = Pallet::<T>::ballot_commitment(election_id, &voter, &candidate, &[7; 32]);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter), election_id, commitment);
 
+		assert_last_event::<T>(Event::VoteCommitted { election_id }.into());
+	}
+
+	#[benchmark]
+	fn reveal_vote() {
+		let voter: T::AccountId = account("Alice", 1u32, 2u32);
+		let candidate: T::AccountId = account("Bob", 2u32, 3u32);
+		let election_id = setup_secret_election::<T>(2, 10, 15);
 		let voter_origin: <T as frame_system::Config>::RuntimeOrigin =
 			RawOrigin::Signed(voter.clone()).into();
-		let candidate_origin: <T as frame_system::Config>::RuntimeOrigin =
-			RawOrigin::Signed(candidate.clone()).into();
-		let root_origin: <T as frame_system::Config>::RuntimeOrigin = RawOrigin::Root.into();
 
+		Pallet::<T>::register_voter(voter_origin.clone()).expect("voter can register");
+		add_candidate::<T>(RawOrigin::Signed(candidate.clone()).into(), election_id);
+		frame_system::Pallet::<T>::set_block_number(2u32.into());
+		let commitment = Pallet::<T>::ballot_commitment(election_id, &voter, &candidate, &[7; 32]);
+		Pallet::<T>::commit_vote(voter_origin, election_id, commitment)
+			.expect("voter can commit");
+		frame_system::Pallet::<T>::set_block_number(12u32.into());
 
-		Pallet::<T>::register_voter(voter_origin.clone());
-		Pallet::<T>::register_candidate(candidate_origin.clone());
-		Pallet::<T>::config_election(root_origin.clone(), start.into(), end.into());
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter), election_id, candidate, [7; 32]);
 
-		frame_system::Pallet::<T>::set_block_number(start.into());
+		assert_last_event::<T>(Event::VoteRevealed { election_id }.into());
+	}
 
-		#[block]
-		{	
-			#[allow(unused_must_use)]
-			Pallet::<T>::give_vote(voter_origin.clone(), candidate.clone());
+	#[benchmark]
+	fn give_conviction_vote() {
+		let voter = funded_voter::<T>();
+		let election_id = setup_weighted_election::<T>(2, 10);
+		let to = setup_delegation_chain::<T>(election_id);
+		Pallet::<T>::delegate(RawOrigin::Signed(voter.clone()).into(), election_id, to)
+			.expect("voter can delegate");
+		let candidate: T::AccountId = account("Candidate", 1u32, 1u32);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter), election_id, candidate, Conviction::Locked6x);
+
+		let until = T::ConvictionPeriod::get() * 32u32.into() + 10u32.into();
+		assert_last_event::<T>(
+			Event::ConvictionVoteSuccess {
+				election_id,
+				conviction: Conviction::Locked6x,
+				until,
+			}
+			.into(),
+		);
+	}
+
+	#[benchmark]
+	fn unlock() {
+		let voter = funded_voter::<T>();
+		let candidate: T::AccountId = account("Bob", 2u32, 3u32);
+		let election_id = setup_weighted_election::<T>(2, 10);
+		add_candidate::<T>(RawOrigin::Signed(candidate.clone()).into(), election_id);
+		frame_system::Pallet::<T>::set_block_number(2u32.into());
+		Pallet::<T>::give_conviction_vote(
+			RawOrigin::Signed(voter.clone()).into(),
+			election_id,
+			candidate,
+			Conviction::Locked1x,
+		)
+		.expect("voter can vote");
+		frame_system::Pallet::<T>::set_block_number(T::ConvictionPeriod::get() + 10u32.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter.clone()), election_id);
+
+		assert_last_event::<T>(Event::FundsUnlocked { who: voter, election_id }.into());
+	}
+
+	/// Registers `MaxCandidates` candidates and `MaxDelegationDepth` funded voters, each
+	/// delegating to the one before it, the first of whom has voted for the first candidate.
+	/// Taking a delegation back then recomputes `MaxVote` over every candidate. Returns the
+	/// last voter of the chain.
+	fn setup_delegation_chain<T: Config>(election_id: ElectionId) -> T::AccountId {
+		let mut candidates: Vec<T::AccountId> = vec![];
+		for i in 0..MaxCandidates::get() {
+			let candidate: T::AccountId = account("Candidate", 1u32, i);
+			add_candidate::<T>(RawOrigin::Signed(candidate.clone()).into(), election_id);
+			candidates.push(candidate);
+		}
+		frame_system::Pallet::<T>::set_block_number(2u32.into());
+
+		let mut previous: Option<T::AccountId> = None;
+		for i in 0..T::MaxDelegationDepth::get() {
+			let voter: T::AccountId = account("Delegate", 1u32, i);
+			let origin: <T as frame_system::Config>::RuntimeOrigin =
+				RawOrigin::Signed(voter.clone()).into();
+			T::Currency::make_free_balance_be(&voter, 1_000_000u32.into());
+			Pallet::<T>::register_voter(origin.clone()).expect("voter can register");
+			match previous {
+				None => Pallet::<T>::give_vote(origin, election_id, candidates[0].clone()),
+				Some(to) => Pallet::<T>::delegate(origin, election_id, to),
+			}
+			.expect("voter can vote or delegate");
+			previous = Some(voter);
+		}
+		previous.expect("MaxDelegationDepth is at least one")
+	}
+
+	#[benchmark]
+	fn delegate() {
+		let voter = funded_voter::<T>();
+		// A token-weighted delegation also locks the balance it carries
+		let election_id = setup_weighted_election::<T>(2, 10);
+		let to = setup_delegation_chain::<T>(election_id);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter.clone()), election_id, to.clone());
+
+		assert_last_event::<T>(Event::Delegated { election_id, who: voter, to }.into());
+	}
+
+	#[benchmark]
+	fn undelegate() {
+		let voter = funded_voter::<T>();
+		let election_id = setup_election::<T>(2, 10);
+		let to = setup_delegation_chain::<T>(election_id);
+		Pallet::<T>::delegate(RawOrigin::Signed(voter.clone()).into(), election_id, to)
+			.expect("voter can delegate");
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter.clone()), election_id);
+
+		assert_last_event::<T>(Event::Undelegated { election_id, who: voter }.into());
+	}
+
+	/// Registers `count` candidates and a voter who votes for the first. Returns the voter and
+	/// the last candidate.
+	fn setup_open_vote<T: Config>(
+		election_id: ElectionId,
+		count: u32,
+	) -> (T::AccountId, T::AccountId) {
+		let voter: T::AccountId = account("Alice", 1u32, 2u32);
+		let mut candidates: Vec<T::AccountId> = vec![];
+		for i in 0..count {
+			let candidate: T::AccountId = account("Candidate", 1u32, i);
+			add_candidate::<T>(RawOrigin::Signed(candidate.clone()).into(), election_id);
+			candidates.push(candidate);
 		}
+		frame_system::Pallet::<T>::set_block_number(2u32.into());
 
-		// self::assert_eq!(Pallet::<T>::candidate_account(candidate).unwrap(), voted_candidate);
-		// self::assert_eq!(Pallet::<T>::voter_account(voter).unwrap(), voted_voter);
-		assert_last_event::<T>(Event::VoteSuccess.into());
+		let origin: <T as frame_system::Config>::RuntimeOrigin =
+			RawOrigin::Signed(voter.clone()).into();
+		Pallet::<T>::register_voter(origin.clone()).expect("voter can register");
+		Pallet::<T>::give_vote(origin, election_id, candidates[0].clone())
+			.expect("voter can vote");
+		(voter, candidates.pop().expect("at least one candidate"))
 	}
 
 	#[benchmark]
-	fn winner() {
-		let root_origin: <T as frame_system::Config>::RuntimeOrigin = RawOrigin::Root.into();
-		let (start, end, inbetween, after_end): (u32, u32, u32, u32) = (2, 10, 7, 15);
+	fn change_vote() {
+		let election_id = setup_election::<T>(2, 10);
+		// Taking votes from the leader recomputes `MaxVote` over all 100 candidates
+		let (voter, candidate) = setup_open_vote::<T>(election_id, 100);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter.clone()), election_id, candidate);
 
-		Pallet::<T>::config_election(root_origin.clone(), start.into(), end.into());
-		frame_system::Pallet::<T>::set_block_number(start.into());
+		assert_last_event::<T>(Event::VoteChanged { election_id, who: voter }.into());
+	}
 
-		let num_candidates =100;
-		let num_voter=1000;
+	#[benchmark]
+	fn revoke_vote() {
+		let election_id = setup_election::<T>(2, 10);
+		let (voter, _) = setup_open_vote::<T>(election_id, 100);
 
-		let mut candidates_list: Vec<T::AccountId > = vec![];
-		for i in 0..num_candidates{
-			let candidate: T::AccountId = account("Candidate", 1u32, i as u32);
-			let candidate_origin: <T as frame_system::Config>::RuntimeOrigin= RawOrigin::Signed(candidate.clone()).into();
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter.clone()), election_id);
+
+		assert_last_event::<T>(Event::VoteRevoked { election_id, who: voter }.into());
+	}
+
+	#[benchmark]
+	fn add_voters(v: Linear<1, 1000>) {
+		let voters: Vec<T::AccountId> = (0..v).map(|i| account("Voter", 1u32, i)).collect();
+		let voters: VoterList<T> = voters.try_into().expect("v is within MaxVoterImport");
+		let authority = T::ElectionAuthority::try_successful_origin()
+			.expect("ElectionAuthority has an origin that passes");
+
+		#[extrinsic_call]
+		_(authority as T::RuntimeOrigin, voters);
+
+		assert_last_event::<T>(Event::VotersAdded { count: v }.into());
+	}
+
+	#[benchmark]
+	fn remove_voter() {
+		let voter: T::AccountId = account("Alice", 1u32, 2u32);
+		let voters: VoterList<T> = vec![voter.clone()].try_into().expect("one voter fits");
+		let authority = T::ElectionAuthority::try_successful_origin()
+			.expect("ElectionAuthority has an origin that passes");
+		Pallet::<T>::add_voters(authority.clone(), voters).expect("voter can be added");
+
+		#[extrinsic_call]
+		_(authority as T::RuntimeOrigin, voter.clone());
+
+		assert_last_event::<T>(Event::VoterRemoved { who: voter }.into());
+	}
+
+	#[benchmark]
+	fn enroll() {
+		let voter: T::AccountId = account("Alice", 1u32, 2u32);
+		let election_id = Pallet::<T>::next_election_id();
+		let window = PhaseWindow { start: 0u32.into(), end: 1u32.into() };
+		Pallet::<T>::create_election(
+			RawOrigin::Root.into(),
+			2u32.into(),
+			10u32.into(),
+			None,
+			Default::default(),
+			ElectionPhases { nomination: None, registration: Some(window) },
+		)
+		.expect("election can be created");
+		Pallet::<T>::register_voter(RawOrigin::Signed(voter.clone()).into())
+			.expect("voter can register");
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter.clone()), election_id);
+
+		assert_last_event::<T>(Event::VoterEnrolled { election_id, who: voter }.into());
+	}
 
-			candidates_list.push(candidate.clone());
-			Pallet::<T>::register_candidate(candidate_origin.clone());
+	#[benchmark]
+	fn cancel_election() {
+		let election_id = setup_election::<T>(2, 10);
+		// Every candidate has a bond to return
+		for i in 0..100u32 {
+			let candidate: T::AccountId = account("Candidate", 1u32, i);
+			add_candidate::<T>(RawOrigin::Signed(candidate).into(), election_id);
 		}
+		let authority = T::ElectionAuthority::try_successful_origin()
+			.expect("ElectionAuthority has an origin that passes");
 
-		let mut voter_origin_list: Vec< <T as frame_system::Config>::RuntimeOrigin > = vec![];
-		for i in 0..num_voter{
-			let voter: T::AccountId = account("Voter", 1u32, i as u32);
-			let voter_origin: <T as frame_system::Config>::RuntimeOrigin= RawOrigin::Signed(voter.clone()).into();
+		#[extrinsic_call]
+		_(authority as T::RuntimeOrigin, election_id);
+
+		assert_last_event::<T>(Event::ElectionCancelled { election_id }.into());
+	}
+
+	#[benchmark]
+	fn extend_voting() {
+		let election_id = setup_election::<T>(2, 10);
+		let authority = T::ElectionAuthority::try_successful_origin()
+			.expect("ElectionAuthority has an origin that passes");
+		let new_end: T::BlockNumber = 20u32.into();
+
+		#[extrinsic_call]
+		_(authority as T::RuntimeOrigin, election_id, new_end);
 
-			voter_origin_list.push(voter_origin.clone());
-			Pallet::<T>::register_voter(voter_origin.clone());
+		assert_last_event::<T>(Event::VotingExtended { election_id, end: new_end }.into());
+	}
+
+	#[benchmark]
+	fn postpone() {
+		let election_id = setup_election::<T>(2, 10);
+		let authority = T::ElectionAuthority::try_successful_origin()
+			.expect("ElectionAuthority has an origin that passes");
+		let (new_start, new_end): (T::BlockNumber, T::BlockNumber) = (12u32.into(), 20u32.into());
+
+		#[extrinsic_call]
+		_(authority as T::RuntimeOrigin, election_id, new_start, new_end);
+
+		assert_last_event::<T>(
+			Event::ElectionPostponed { election_id, start: new_start, end: new_end }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn purge_election(n: Linear<1, 1100>) {
+		let election_id = setup_election::<T>(2, 10);
+		// Up to 100 candidate records, and a ballot for every other record
+		let candidates = n.min(100);
+		for i in 0..candidates {
+			let candidate: T::AccountId = account("Candidate", 1u32, i);
+			add_candidate::<T>(RawOrigin::Signed(candidate).into(), election_id);
 		}
+		let candidate: T::AccountId = account("Candidate", 1u32, 0u32);
+		frame_system::Pallet::<T>::set_block_number(7u32.into());
+		for i in 0..n - candidates {
+			let voter: T::AccountId = account("Voter", 1u32, i);
+			let origin: T::RuntimeOrigin = RawOrigin::Signed(voter).into();
+			Pallet::<T>::register_voter(origin.clone()).expect("voter can register");
+			Pallet::<T>::give_vote(origin, election_id, candidate.clone()).expect("voter can vote");
+		}
+		frame_system::Pallet::<T>::set_block_number(15u32.into());
+		let caller: T::AccountId = account("Alice", 1u32, 2u32);
+		Pallet::<T>::winner(RawOrigin::Signed(caller.clone()).into(), election_id, n - candidates)
+			.expect("election can be counted");
 
-		frame_system::Pallet::<T>::set_block_number(inbetween.into());
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), election_id, n);
 
-		let outer_id= voter_origin_list.len() / candidates_list.len(); // 10
-		let inner_id: usize = 100;
-		for i in 1..=outer_id{
-			let start= inner_id*(i-1);
-			let end= inner_id*(i); 
+		assert_last_event::<T>(Event::ElectionPurged { election_id }.into());
+	}
 
-			for voter_index in start..end{
-				let candidate_index= voter_index - start;
-				let voter_origin = voter_origin_list.get(voter_index).unwrap().clone();
-				let candidate = candidates_list.get(candidate_index).unwrap().clone();
-				Pallet::<T>::give_vote(voter_origin, candidate );
-			}
+	/// Plurality, approval, score and quadratic elections count as their ballots are cast, so
+	/// finalizing them only walks the candidates.
+	#[benchmark]
+	fn winner(c: Linear<1, 100>) {
+		let election_id = setup_election::<T>(2, 10);
+		let candidates: Vec<T::AccountId> = (0..c).map(|i| account("Candidate", 1u32, i)).collect();
+		for candidate in candidates.iter() {
+			add_candidate::<T>(RawOrigin::Signed(candidate.clone()).into(), election_id);
 		}
+		frame_system::Pallet::<T>::set_block_number(2u32.into());
 
-		frame_system::Pallet::<T>::set_block_number(after_end.into());
+		// Every candidate ties on one vote, so all of them are written back as winners
+		for (i, candidate) in candidates.iter().enumerate() {
+			let voter: T::AccountId = account("Voter", 1u32, i as u32);
+			let origin: <T as frame_system::Config>::RuntimeOrigin =
+				RawOrigin::Signed(voter).into();
+			Pallet::<T>::register_voter(origin.clone()).expect("voter can register");
+			Pallet::<T>::give_vote(origin, election_id, candidate.clone()).expect("voter can vote");
+		}
+		frame_system::Pallet::<T>::set_block_number(15u32.into());
 		let caller: T::AccountId = account("Alice", 1u32, 2u32);
-		let caller: <T as frame_system::Config>::RuntimeOrigin= RawOrigin::Signed(caller.clone()).into();
-		
-		#[block]
-		{	
-			Pallet::<T>::winner(caller.clone());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), election_id, c);
+
+		assert_last_event::<T>(Event::WinnerVecStored { election_id }.into());
+	}
+
+	fn setup_ranked_election<T: Config>(method: VotingMethod, c: u32, b: u32) -> ElectionId {
+		let election_id = setup_election_with::<T>(2, 10, method);
+		let candidates: Vec<T::AccountId> = (0..c).map(|i| account("Candidate", 1u32, i)).collect();
+		for candidate in candidates.iter() {
+			add_candidate::<T>(RawOrigin::Signed(candidate.clone()).into(), election_id);
+		}
+
+		// Every ballot ranks every candidate, starting from a different one
+		frame_system::Pallet::<T>::set_block_number(7u32.into());
+		for i in 0..b {
+			let voter: T::AccountId = account("Voter", 1u32, i);
+			let origin: T::RuntimeOrigin = RawOrigin::Signed(voter).into();
+			let mut ranking = candidates.clone();
+			ranking.rotate_left((i % c) as usize);
+			let ranking: CandidateList<T> = ranking.try_into().expect("c is within MaxCandidates");
+			Pallet::<T>::register_voter(origin.clone()).expect("voter can register");
+			Pallet::<T>::give_ranked_vote(origin, election_id, ranking).expect("voter can vote");
 		}
+		frame_system::Pallet::<T>::set_block_number(15u32.into());
+		election_id
+	}
+
+	#[benchmark]
+	fn winner_ranked(c: Linear<2, 100>, b: Linear<1, 1000>) {
+		let election_id = setup_ranked_election::<T>(VotingMethod::Schulze, c, b);
+		let caller: T::AccountId = account("Alice", 1u32, 2u32);
+
+		#[extrinsic_call]
+		winner(RawOrigin::Signed(caller), election_id, b);
+
+		assert_last_event::<T>(Event::WinnerVecStored { election_id }.into());
+	}
+
+	// The rotated ballots keep the counts level, so a single seat is only filled once every
+	// other candidate has been eliminated, one round each
+	#[benchmark]
+	fn winner_stv(c: Linear<2, 100>, b: Linear<1, 1000>) {
+		let election_id = setup_ranked_election::<T>(VotingMethod::Stv, c, b);
+		let caller: T::AccountId = account("Alice", 1u32, 2u32);
+
+		#[extrinsic_call]
+		winner(RawOrigin::Signed(caller), election_id, b);
 
-		assert_last_event::<T>(Event::WinnerVecStored.into());
+		assert_last_event::<T>(Event::WinnerVecStored { election_id }.into());
 	}
 }
 
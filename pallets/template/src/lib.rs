@@ -1,13 +1,16 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod benchmarking;
+pub mod methods;
 #[cfg(test)]
 mod mock;
+pub mod tally;
 #[cfg(test)]
 mod tests;
 
 // use frame_support::BoundedVec;
 pub use self::pallet::*;
+pub use methods::{BuiltinTallies, TallyMethod, TallyMethods};
 
 mod weights;
 use frame_support::pallet_prelude::Weight;
@@ -15,26 +18,143 @@ pub use weights::TemplateWeightInfo;
 pub trait WeightInfo {
 	fn add_voter() -> Weight;
 	fn register_candidate() -> Weight;
-	fn config_election() -> Weight;
+	fn update_candidate_profile() -> Weight;
+	fn create_election() -> Weight;
 	fn give_vote() -> Weight;
-	fn winner() -> Weight;
+	fn winner(c: u32) -> Weight;
+	fn winner_ranked(c: u32, b: u32) -> Weight;
+	fn winner_stv(c: u32, b: u32) -> Weight;
+	fn commit_vote() -> Weight;
+	fn reveal_vote() -> Weight;
+	fn give_ranked_vote() -> Weight;
+	fn give_approval_vote() -> Weight;
+	fn give_score_vote() -> Weight;
+	fn give_votes() -> Weight;
+	fn give_conviction_vote() -> Weight;
+	fn unlock() -> Weight;
+	fn delegate() -> Weight;
+	fn undelegate() -> Weight;
+	fn change_vote() -> Weight;
+	fn revoke_vote() -> Weight;
+	fn add_voters(v: u32) -> Weight;
+	fn remove_voter() -> Weight;
+	fn enroll() -> Weight;
+	fn cancel_election() -> Weight;
+	fn extend_voting() -> Weight;
+	fn postpone() -> Weight;
+	fn purge_election(n: u32) -> Weight;
 }
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use frame_support::pallet_prelude::*;
+	use frame_support::{
+		pallet_prelude::*,
+		sp_runtime::{
+			traits::{Hash, One, Saturating, TrailingZeroInput, UniqueSaturatedInto, Zero},
+			Perbill,
+		},
+		sp_std::{cmp::Reverse, prelude::*},
+		storage::{with_transaction, TransactionOutcome},
+		traits::{
+			Currency, LockIdentifier, LockableCurrency, OnUnbalanced, Randomness,
+			ReservableCurrency, WithdrawReasons,
+		},
+	};
 	use frame_system::pallet_prelude::*;
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
+	/// Identifier handed out by `create_election`, scoping candidates, voters and results.
+	pub type ElectionId = u32;
+
+	/// Bounded text used for a candidate's display name and affiliation.
+	pub type ProfileText = BoundedVec<u8, ConstU32<100>>;
+
+	/// Storage key at which `clear_prefix` stopped, for the next batch of a purge to resume from.
+	pub type PurgeCursor = BoundedVec<u8, ConstU32<256>>;
+
+	/// Most candidates a single election can hold.
+	pub type MaxCandidates = ConstU32<100>;
+
+	/// Candidates of one election, as listed in a ballot or a result.
+	pub type CandidateList<T> = BoundedVec<<T as frame_system::Config>::AccountId, MaxCandidates>;
+
+	/// Most accounts a single `add_voters` call can put on the voter roll.
+	pub type MaxVoterImport = ConstU32<1000>;
+
+	/// Accounts added to the voter roll in one call.
+	pub type VoterList<T> = BoundedVec<<T as frame_system::Config>::AccountId, MaxVoterImport>;
+
+	/// Candidates of one election, each paired with a score or a count.
+	pub type ScoreList<T> =
+		BoundedVec<(<T as frame_system::Config>::AccountId, u32), MaxCandidates>;
+
+	/// Weight a ballot carries, and the unit of a candidate's `vote_count`. Token-weighted
+	/// elections count balances, so this is wider than a ballot count.
+	pub type VoteWeight = u128;
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::NegativeImbalance;
+
+	/// Lock placed on the balance behind a conviction vote.
+	pub const CONVICTION_LOCK_ID: LockIdentifier = *b"election";
+
 	/* --------------------------------- Config --------------------------------- */
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
 		type WeightInfo: WeightInfo;
+
+		/// Counting rules elections can pick from. `BuiltinTallies` offers every `VotingMethod`
+		/// except `Custom`.
+		type TallyMethods: TallyMethods<Self>;
+
+		/// Balances that token-weighted elections weigh ballots by, and that conviction votes
+		/// lock.
+		type Currency: ReservableCurrency<Self::AccountId>
+			+ LockableCurrency<Self::AccountId, Moment = Self::BlockNumber>;
+
+		/// Blocks after the end of an election that one lock period of a conviction vote lasts.
+		#[pallet::constant]
+		type ConvictionPeriod: Get<Self::BlockNumber>;
+
+		/// Longest chain of delegations an election allows, counted in hops.
+		#[pallet::constant]
+		type MaxDelegationDepth: Get<u32>;
+
+		/// Origin that keeps the voter roll.
+		type ElectionAuthority: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Deposit reserved from a candidate when they register.
+		#[pallet::constant]
+		type CandidacyBond: Get<BalanceOf<Self>>;
+
+		/// Share of an election's votes a candidate needs to get their bond back.
+		#[pallet::constant]
+		type CandidacyBondThreshold: Get<Perbill>;
+
+		/// Where the bonds of candidates below `CandidacyBondThreshold` go, such as a treasury.
+		type ForfeitedBond: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// Most elections that can close in the same block, bounding the tallying
+		/// `on_initialize` does in one block.
+		#[pallet::constant]
+		type MaxElectionsPerBlock: Get<u32>;
+
+		/// Most weight `on_initialize` spends tallying elections in one block. An election whose
+		/// tally would go over it is left for `winner` to count.
+		#[pallet::constant]
+		type MaxFinalizationWeight: Get<Weight>;
+
+		/// Source of the draw that settles a tie under `TieBreak::Random`.
+		type Randomness: Randomness<Self::Hash, Self::BlockNumber>;
 	}
 	/* --------------------------------- Custom --------------------------------- */
 	#[derive(Clone, Eq, PartialEq, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
@@ -42,85 +162,938 @@ pub mod pallet {
 	pub struct VoterInfo<T: Config> {
 		vote_status: bool,
 		voted_for: Option<T::AccountId>,
+		// Sealed ballot of a commit-reveal election, opened by `reveal_vote`
+		commitment: Option<T::Hash>,
+		// Credits of the election's budget spent on quadratic votes so far
+		credits_spent: u32,
+		// Weight of the voter's ballot, snapshotted when it was cast or delegated
+		weight: VoteWeight,
+		// Voter this voter has handed their vote to, until they vote themselves
+		delegate: Option<T::AccountId>,
+		// Weight handed to this voter by everyone delegating to them, directly or transitively
+		delegated: VoteWeight,
+		// Longest chain of delegations ending at this voter. Never shrinks, so it may overstate
+		// the chain after an `undelegate`.
+		delegation_depth: u32,
 	}
 
 	impl<T: Config> VoterInfo<T> {
 		pub fn new() -> Self {
-			VoterInfo { vote_status: false, voted_for: None }
+			VoterInfo {
+				vote_status: false,
+				voted_for: None,
+				commitment: None,
+				credits_spent: 0,
+				weight: 0,
+				delegate: None,
+				delegated: 0,
+				delegation_depth: 0,
+			}
 		}
 		pub fn set(val: bool, who: T::AccountId) -> Self {
-			VoterInfo { vote_status: val, voted_for: Some(who) }
+			VoterInfo { vote_status: val, voted_for: Some(who), weight: 1, ..Self::new() }
+		}
+		pub fn committed(commitment: T::Hash) -> Self {
+			VoterInfo { vote_status: true, commitment: Some(commitment), weight: 1, ..Self::new() }
+		}
+		// Voted with a ballot that names more than one candidate
+		pub fn multi_candidate() -> Self {
+			VoterInfo { vote_status: true, weight: 1, ..Self::new() }
+		}
+		// Voted in a quadratic election, having spent `credits` of the budget
+		pub fn quadratic(credits: u32) -> Self {
+			VoterInfo { vote_status: true, credits_spent: credits, weight: 1, ..Self::new() }
+		}
+		pub fn credits_spent(&self) -> u32 {
+			self.credits_spent
+		}
+		pub fn with_weight(mut self, weight: VoteWeight) -> Self {
+			self.weight = weight;
+			self
+		}
+		pub fn weight(&self) -> VoteWeight {
+			self.weight
+		}
+		// Keeps the delegations `previous` received, for a voter casting their own ballot
+		pub fn with_delegations_of(mut self, previous: &Self) -> Self {
+			self.delegated = previous.delegated;
+			self.delegation_depth = previous.delegation_depth;
+			self
+		}
+		pub fn delegate(&self) -> Option<&T::AccountId> {
+			self.delegate.as_ref()
+		}
+		pub fn delegated(&self) -> VoteWeight {
+			self.delegated
 		}
 	}
 
 	#[derive(Clone, Eq, PartialEq, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
-	pub struct CandidateInfo {
-		// name: BoundedVec<u8, ConstU32<100>>,
-		vote_count: u32,
+	#[scale_info(skip_type_params(T))]
+	pub struct CandidateInfo<T: Config> {
+		name: ProfileText,
+		party: Option<ProfileText>,
+		// Hash of the candidate's manifesto, which itself is kept off-chain
+		manifesto_hash: T::Hash,
+		vote_count: VoteWeight,
+		// Bond reserved at registration, zeroed once the election settles it
+		bond: BalanceOf<T>,
+		// How many candidates registered for the election before this one
+		registration_index: u32,
 	}
 
-	impl CandidateInfo {
-		pub fn new() -> Self {
-			CandidateInfo { vote_count: 0 }
+	impl<T: Config> CandidateInfo<T> {
+		pub fn new(name: ProfileText, party: Option<ProfileText>, manifesto_hash: T::Hash) -> Self {
+			CandidateInfo {
+				name,
+				party,
+				manifesto_hash,
+				vote_count: 0,
+				bond: Zero::zero(),
+				registration_index: 0,
+			}
+		}
+		pub fn set(
+			name: ProfileText,
+			party: Option<ProfileText>,
+			manifesto_hash: T::Hash,
+			val: VoteWeight,
+		) -> Self {
+			CandidateInfo {
+				name,
+				party,
+				manifesto_hash,
+				vote_count: val,
+				bond: Zero::zero(),
+				registration_index: 0,
+			}
+		}
+		pub fn with_bond(mut self, bond: BalanceOf<T>) -> Self {
+			self.bond = bond;
+			self
+		}
+		pub fn with_registration_index(mut self, registration_index: u32) -> Self {
+			self.registration_index = registration_index;
+			self
+		}
+		pub fn vote_count(&self) -> VoteWeight {
+			self.vote_count
+		}
+		pub fn bond(&self) -> BalanceOf<T> {
+			self.bond
+		}
+		pub fn registration_index(&self) -> u32 {
+			self.registration_index
+		}
+	}
+
+	/// How the ballots of an election are cast and counted.
+	#[derive(
+		Clone, Copy, Eq, PartialEq, Default, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo,
+	)]
+	pub enum VotingMethod {
+		// One candidate per ballot through `give_vote`, the most votes wins
+		#[default]
+		Plurality,
+		// Ranked ballots through `give_ranked_vote`, counted by instant-runoff
+		InstantRunoff,
+		// Every candidate approved through `give_approval_vote` gets one vote, the most votes wins
+		Approval,
+		// Candidates are scored from 0 to `max_score` through `give_score_vote`, the highest
+		// total score wins
+		Score,
+		// Score ballots, followed by an automatic runoff between the two highest scorers
+		Star,
+		// Ranked ballots through `give_ranked_vote`, filling `seats` by single transferable vote
+		Stv,
+		// Ranked ballots through `give_ranked_vote`, scored by ranking position, the most points
+		// wins
+		Borda,
+		// Ranked ballots through `give_ranked_vote`, compared pairwise by the Schulze method
+		Schulze,
+		// Ranked ballots through `give_ranked_vote`, counted by a rule the runtime adds through
+		// `Config::TallyMethods`
+		Custom(u8),
+		// Voters spread a budget of `credits` through `give_votes`, `k` votes for one candidate
+		// costing `k * k` credits, the most votes wins
+		Quadratic,
+	}
+
+	impl VotingMethod {
+		pub fn is_scored(&self) -> bool {
+			matches!(self, VotingMethod::Score | VotingMethod::Star)
+		}
+
+		pub fn is_ranked(&self) -> bool {
+			matches!(
+				self,
+				VotingMethod::InstantRunoff
+					| VotingMethod::Stv
+					| VotingMethod::Borda
+					| VotingMethod::Schulze
+					| VotingMethod::Custom(_)
+			)
+		}
+	}
+
+	/// Rules an election is run by, fixed when it is created.
+	#[derive(
+		Clone, Eq, PartialEq, Default, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo,
+	)]
+	pub struct ElectionSettings {
+		pub method: VotingMethod,
+		// Highest score a ballot can give a candidate in a score or STAR election
+		pub max_score: u32,
+		// Number of candidates an STV election elects
+		pub seats: u32,
+		// Credits each voter can spend in a quadratic election
+		pub credits: u32,
+		// What a ballot given through `give_vote` or `commit_vote` weighs
+		pub weighting: VoteWeighting,
+		// Whether voters can back a free-balance ballot with a `Conviction`. Plain ballots then
+		// count as `Conviction::None`
+		pub conviction: bool,
+		// Which voters may take part
+		pub eligibility: Eligibility,
+		// How a tie for the win is settled. STV elections fill several seats and keep every tie
+		pub tie_break: TieBreak,
+		// Share of the ballots a plurality winner needs, if any
+		pub majority: Option<MajorityRule>,
+		// Share of the electorate that must vote for the election to be valid, if any
+		pub quorum: Option<Perbill>,
+	}
+
+	/// A share of the ballots the winner must get more than. Without it, the two leading
+	/// candidates go to a runoff.
+	#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+	pub struct MajorityRule {
+		pub threshold: Perbill,
+		// Length of the runoff's voting phase, in blocks
+		pub runoff_duration: u32,
+	}
+
+	/// Who may vote in an election.
+	#[derive(
+		Clone, Copy, Eq, PartialEq, Default, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo,
+	)]
+	pub enum Eligibility {
+		// An open poll: self-registered voters and voters on the roll
+		#[default]
+		SelfRegistered,
+		// Only voters the election authority has put on the voter roll
+		VoterRoll,
+	}
+
+	/// Where an election stands in its lifecycle. The first four follow from the current block,
+	/// the last three are final and kept in `ClosedElections`.
+	#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+	pub enum ElectionStatus {
+		// Created, voting has not opened yet. Candidates can register in the nomination phase
+		Scheduled,
+		// Within the election's registration phase, voters enroll
+		Registration,
+		// Between the start and end blocks, ballots are cast
+		Voting,
+		// Voting has closed, sealed ballots are revealed and the election awaits its count
+		Tallying,
+		// Counted, with its winners in `MaxVoteCandidate`
+		Finalized,
+		// Called off by the election authority
+		Cancelled,
+		// Counted, but void because its turnout fell short of its quorum
+		Invalid,
+	}
+
+	/// How many of an election's electorate cast a ballot, as counted when it is finalized.
+	#[derive(
+		Clone, Copy, Eq, PartialEq, Default, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo,
+	)]
+	pub struct Turnout {
+		// Voters whose ballot is cast, sealed ballots included
+		pub voted: u32,
+		// Voters the election admits: its enrolled voters if it has a registration phase
+		pub electorate: u32,
+	}
+
+	impl Turnout {
+		/// Whether at least `quorum` of the electorate voted.
+		pub fn meets(&self, quorum: Perbill) -> bool {
+			self.voted >= quorum.mul_ceil(self.electorate)
+		}
+	}
+
+	/// A counted election, as archived in `ElectionResults`.
+	#[derive(Clone, Eq, PartialEq, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+	#[scale_info(skip_type_params(T))]
+	pub struct ElectionResult<T: Config> {
+		// `Finalized`, or `Invalid` for an election short of its quorum
+		pub status: ElectionStatus,
+		pub method: VotingMethod,
+		// Every candidate's final `vote_count` (first preferences in a ranked election), in
+		// registration order
+		pub counts: BoundedVec<(T::AccountId, VoteWeight), MaxCandidates>,
+		// Empty for an invalid election, or one whose majority went to a runoff
+		pub winners: CandidateList<T>,
+		pub turnout: Turnout,
+		pub finalized_at: T::BlockNumber,
+	}
+
+	/// How far the removal of a closed election's ballots and candidate records has got.
+	#[derive(
+		Clone, Eq, PartialEq, Default, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo,
+	)]
+	pub struct PurgeProgress {
+		// Index of the map being cleared, in the order `Pallet::purge_records` clears them
+		pub stage: u8,
+		pub cursor: Option<PurgeCursor>,
+	}
+
+	/// How a single-winner election that ends in a tie is settled.
+	#[derive(
+		Clone, Copy, Eq, PartialEq, Default, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo,
+	)]
+	pub enum TieBreak {
+		// Every tied candidate is stored as a winner
+		#[default]
+		KeepAll,
+		// One of the tied candidates is drawn through `Config::Randomness`
+		Random,
+		// The tied candidate who registered first wins
+		EarliestRegistration,
+		// A plurality runoff among the tied candidates opens as soon as the election is counted
+		Runoff,
+	}
+
+	/// How a tie for the win was settled, as kept in `TieBreaks`.
+	#[derive(Clone, Eq, PartialEq, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+	#[scale_info(skip_type_params(T))]
+	pub struct TieBreakOutcome<T: Config> {
+		pub policy: TieBreak,
+		pub tied: CandidateList<T>,
+		// The candidate the policy picked, unless it left the tie to a runoff
+		pub winner: Option<T::AccountId>,
+		// The election scheduled under `TieBreak::Runoff`
+		pub runoff: Option<ElectionId>,
+	}
+
+	impl<T: Config> TieBreakOutcome<T> {
+		/// The winners the election is left with: the picked candidate, or every tied one
+		/// while a runoff decides between them.
+		pub fn winners(&self) -> CandidateList<T> {
+			match &self.winner {
+				Some(winner) => BoundedVec::truncate_from(vec![winner.clone()]),
+				None => self.tied.clone(),
+			}
+		}
+	}
+
+	/// What a plurality ballot weighs. Balances are snapshotted when the ballot is cast.
+	#[derive(
+		Clone, Copy, Eq, PartialEq, Default, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo,
+	)]
+	pub enum VoteWeighting {
+		// Every ballot counts once
+		#[default]
+		OnePerAccount,
+		// A ballot weighs the voter's free balance
+		FreeBalance,
+		// A ballot weighs the voter's reserved balance
+		ReservedBalance,
+	}
+
+	/// How strongly a voter backs a conviction vote. Each level multiplies the ballot's weight,
+	/// in return for locking the voter's balance for a number of periods after the election.
+	#[derive(
+		Clone, Copy, Eq, PartialEq, Default, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo,
+	)]
+	pub enum Conviction {
+		// A tenth of the balance counts, locked only until the election ends
+		#[default]
+		None,
+		// Weight x1, locked for 1 period
+		Locked1x,
+		// Weight x2, locked for 2 periods
+		Locked2x,
+		// Weight x3, locked for 4 periods
+		Locked3x,
+		// Weight x4, locked for 8 periods
+		Locked4x,
+		// Weight x5, locked for 16 periods
+		Locked5x,
+		// Weight x6, locked for 32 periods
+		Locked6x,
+	}
+
+	impl Conviction {
+		/// Weight of a ballot backed by `balance` at this conviction.
+		pub fn votes(&self, balance: VoteWeight) -> VoteWeight {
+			match self {
+				Conviction::None => balance / 10,
+				Conviction::Locked1x => balance,
+				Conviction::Locked2x => balance.saturating_mul(2),
+				Conviction::Locked3x => balance.saturating_mul(3),
+				Conviction::Locked4x => balance.saturating_mul(4),
+				Conviction::Locked5x => balance.saturating_mul(5),
+				Conviction::Locked6x => balance.saturating_mul(6),
+			}
+		}
+
+		/// Number of `ConvictionPeriod`s the balance stays locked after the election ends.
+		pub fn lock_periods(&self) -> u32 {
+			match self {
+				Conviction::None => 0,
+				Conviction::Locked1x => 1,
+				Conviction::Locked2x => 2,
+				Conviction::Locked3x => 4,
+				Conviction::Locked4x => 8,
+				Conviction::Locked5x => 16,
+				Conviction::Locked6x => 32,
+			}
 		}
-		pub fn set(val: u32) -> Self {
-			CandidateInfo { vote_count: val }
+	}
+
+	/// Balance a vote locked, and for how many blocks after the election's end. The expiry is
+	/// measured from the end `unlock` finds, so extending or postponing voting moves it too.
+	#[derive(Clone, Eq, PartialEq, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+	#[scale_info(skip_type_params(T))]
+	pub struct ConvictionLock<T: Config> {
+		pub amount: BalanceOf<T>,
+		pub after_end: T::BlockNumber,
+	}
+
+	/// One round of an STV count, as kept in `StvRounds` for auditing.
+	#[derive(Clone, Eq, PartialEq, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+	#[scale_info(skip_type_params(T))]
+	pub struct StvRoundInfo<T: Config> {
+		// Candidates still in the race, with their count in millionths of a vote
+		pub counts: BoundedVec<(T::AccountId, u64), MaxCandidates>,
+		pub action: tally::StvAction<T::AccountId>,
+	}
+
+	/// An inclusive range of blocks.
+	#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+	pub struct PhaseWindow<BlockNumber> {
+		pub start: BlockNumber,
+		pub end: BlockNumber,
+	}
+
+	impl<BlockNumber: PartialOrd> PhaseWindow<BlockNumber> {
+		pub fn contains(&self, block: &BlockNumber) -> bool {
+			&self.start <= block && block <= &self.end
 		}
 	}
 
+	/// The phases of an election before voting opens. Both must close before the voting phase.
+	#[derive(
+		Clone, Copy, Eq, PartialEq, Default, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo,
+	)]
+	pub struct ElectionPhases<BlockNumber> {
+		// When candidates can register. Without one, they can register until voting opens
+		pub nomination: Option<PhaseWindow<BlockNumber>>,
+		// When voters enroll through `enroll`. Without one, every eligible voter can vote
+		// without enrolling
+		pub registration: Option<PhaseWindow<BlockNumber>>,
+	}
+
 	#[derive(Clone, Eq, PartialEq, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
 	#[scale_info(skip_type_params(T))]
 	pub struct ElectionInfo<T: Config> {
 		start_block: Option<T::BlockNumber>,
 		end_block: Option<T::BlockNumber>,
+		// Set for commit-reveal elections: ballots are opened after `end_block` up to this block
+		reveal_end: Option<T::BlockNumber>,
+		settings: ElectionSettings,
+		phases: ElectionPhases<T::BlockNumber>,
 	}
 
 	impl<T: Config> ElectionInfo<T> {
 		fn _new() -> Self {
-			ElectionInfo { start_block: None, end_block: None }
+			ElectionInfo {
+				start_block: None,
+				end_block: None,
+				reveal_end: None,
+				settings: Default::default(),
+				phases: Default::default(),
+			}
+		}
+
+		pub fn set(
+			start: T::BlockNumber,
+			end: T::BlockNumber,
+			reveal_end: Option<T::BlockNumber>,
+			settings: ElectionSettings,
+		) -> Self {
+			ElectionInfo {
+				start_block: Some(start),
+				end_block: Some(end),
+				reveal_end,
+				settings,
+				phases: Default::default(),
+			}
+		}
+
+		pub fn with_phases(mut self, phases: ElectionPhases<T::BlockNumber>) -> Self {
+			self.phases = phases;
+			self
+		}
+
+		pub fn phases(&self) -> ElectionPhases<T::BlockNumber> {
+			self.phases
+		}
+
+		pub fn method(&self) -> VotingMethod {
+			self.settings.method
+		}
+
+		pub fn max_score(&self) -> u32 {
+			self.settings.max_score
+		}
+
+		pub fn seats(&self) -> u32 {
+			self.settings.seats
+		}
+
+		pub fn credits(&self) -> u32 {
+			self.settings.credits
+		}
+
+		pub fn weighting(&self) -> VoteWeighting {
+			self.settings.weighting
+		}
+
+		pub fn conviction(&self) -> bool {
+			self.settings.conviction
+		}
+
+		pub fn eligibility(&self) -> Eligibility {
+			self.settings.eligibility
 		}
 
-		pub fn set(start: T::BlockNumber, end: T::BlockNumber) -> Self {
-			ElectionInfo { start_block: Some(start), end_block: Some(end) }
+		pub fn tie_break(&self) -> TieBreak {
+			self.settings.tie_break
 		}
 
-		pub fn ensure_election_progress() -> DispatchResult {
+		pub fn majority(&self) -> Option<MajorityRule> {
+			self.settings.majority
+		}
+
+		pub fn quorum(&self) -> Option<Perbill> {
+			self.settings.quorum
+		}
+
+		pub fn is_commit_reveal(&self) -> bool {
+			self.reveal_end.is_some()
+		}
+
+		/// Whether voters can call `give_conviction_vote`, which the election opts into with
+		/// `ElectionSettings::conviction`.
+		pub fn takes_conviction(&self) -> bool {
+			self.conviction()
+		}
+
+		/// Block at which `on_initialize` finalizes the election: the one after voting, or
+		/// the reveal window, closes.
+		pub fn finalize_at(&self) -> T::BlockNumber {
+			self.reveal_end.or(self.end_block).unwrap().saturating_add(One::one())
+		}
+
+		/// The election moved to vote from `start` to `end`. A reveal window keeps its length.
+		fn rescheduled(&self, start: T::BlockNumber, end: T::BlockNumber) -> Self {
+			let reveal_end = self.reveal_end.map(|reveal_end| {
+				end.saturating_add(reveal_end.saturating_sub(self.end_block.unwrap()))
+			});
+			ElectionInfo {
+				start_block: Some(start),
+				end_block: Some(end),
+				reveal_end,
+				..self.clone()
+			}
+		}
+
+		fn ensure_not_cancelled(election_id: ElectionId) -> DispatchResult {
+			ensure!(
+				ClosedElections::<T>::get(election_id) != Some(ElectionStatus::Cancelled),
+				Error::<T>::ElectionCancelled
+			);
+			Ok(())
+		}
+
+		pub fn ensure_election_progress(election_id: ElectionId) -> DispatchResult {
 			let block_number = <frame_system::Pallet<T>>::block_number();
 
-			ensure!(ElectionConfig::<T>::get().is_some(), Error::<T>::ElectionNotConfigured);
-			let election = ElectionConfig::<T>::get().unwrap();
+			ensure!(
+				ElectionConfig::<T>::contains_key(election_id),
+				Error::<T>::ElectionNotConfigured
+			);
+			let election = ElectionConfig::<T>::get(election_id).unwrap();
+			Self::ensure_not_cancelled(election_id)?;
+			ensure!(!ClosedElections::<T>::contains_key(election_id), Error::<T>::ElectionClosed);
 
 			ensure!(block_number >= election.start_block.unwrap(), Error::<T>::ElectionNotStarted);
 			ensure!(block_number <= election.end_block.unwrap(), Error::<T>::ElectionEnded);
 
 			Ok(())
 		}
+
+		pub fn ensure_election_not_started(election_id: ElectionId) -> DispatchResult {
+			let block_number = <frame_system::Pallet<T>>::block_number();
+
+			ensure!(
+				ElectionConfig::<T>::contains_key(election_id),
+				Error::<T>::ElectionNotConfigured
+			);
+			let election = ElectionConfig::<T>::get(election_id).unwrap();
+			Self::ensure_not_cancelled(election_id)?;
+
+			ensure!(block_number < election.start_block.unwrap(), Error::<T>::ElectionStarted);
+
+			Ok(())
+		}
+
+		pub fn ensure_nomination_progress(election_id: ElectionId) -> DispatchResult {
+			let block_number = <frame_system::Pallet<T>>::block_number();
+
+			ensure!(
+				ElectionConfig::<T>::contains_key(election_id),
+				Error::<T>::ElectionNotConfigured
+			);
+			let election = ElectionConfig::<T>::get(election_id).unwrap();
+			Self::ensure_not_cancelled(election_id)?;
+
+			match election.phases.nomination {
+				Some(nomination) => {
+					ensure!(block_number >= nomination.start, Error::<T>::NominationNotStarted);
+					ensure!(block_number <= nomination.end, Error::<T>::NominationEnded);
+				},
+				None => {
+					ensure!(
+						block_number < election.start_block.unwrap(),
+						Error::<T>::NominationEnded
+					);
+				},
+			}
+
+			Ok(())
+		}
+
+		pub fn ensure_registration_progress(election_id: ElectionId) -> DispatchResult {
+			let block_number = <frame_system::Pallet<T>>::block_number();
+
+			ensure!(
+				ElectionConfig::<T>::contains_key(election_id),
+				Error::<T>::ElectionNotConfigured
+			);
+			let election = ElectionConfig::<T>::get(election_id).unwrap();
+			Self::ensure_not_cancelled(election_id)?;
+
+			let registration =
+				election.phases.registration.ok_or(Error::<T>::RegistrationNotScheduled)?;
+			ensure!(block_number >= registration.start, Error::<T>::RegistrationNotStarted);
+			ensure!(block_number <= registration.end, Error::<T>::RegistrationEnded);
+
+			Ok(())
+		}
+
+		pub fn ensure_reveal_progress(election_id: ElectionId) -> DispatchResult {
+			let block_number = <frame_system::Pallet<T>>::block_number();
+
+			ensure!(
+				ElectionConfig::<T>::contains_key(election_id),
+				Error::<T>::ElectionNotConfigured
+			);
+			let election = ElectionConfig::<T>::get(election_id).unwrap();
+			Self::ensure_not_cancelled(election_id)?;
+
+			ensure!(election.is_commit_reveal(), Error::<T>::SecretBallotNotEnabled);
+			ensure!(block_number > election.end_block.unwrap(), Error::<T>::RevealNotStarted);
+			ensure!(block_number <= election.reveal_end.unwrap(), Error::<T>::RevealEnded);
+
+			Ok(())
+		}
 	}
 
 	/* --------------------------------- storage -------------------------------- */
 
+	/// Accounts registered as voters. Registration is global and carries over between elections.
+	#[pallet::storage]
+	pub type RegisteredVoters<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	/// Accounts the election authority has put on the voter roll. They can vote in every
+	/// election, and are the only voters of elections restricted to the roll.
+	#[pallet::storage]
+	#[pallet::getter(fn on_voter_roll)]
+	pub type VoterRoll<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	/// Voters who enrolled in an election with a registration phase.
+	#[pallet::storage]
+	#[pallet::getter(fn enrolled_voter)]
+	pub type EnrolledVoters<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ElectionId,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
+	/// Number of accounts on the voter roll.
+	#[pallet::storage]
+	#[pallet::getter(fn voter_roll_count)]
+	pub type VoterRollCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Number of accounts that are registered voters, on the voter roll, or both: the
+	/// electorate of an open poll.
+	#[pallet::storage]
+	#[pallet::getter(fn open_electorate_count)]
+	pub type OpenElectorateCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Number of voters enrolled in an election with a registration phase.
+	#[pallet::storage]
+	#[pallet::getter(fn enrolled_voter_count)]
+	pub type EnrolledVoterCount<T: Config> =
+		StorageMap<_, Twox64Concat, ElectionId, u32, ValueQuery>;
+
+	/// Number of voters with a ballot in an election, sealed ballots included.
+	#[pallet::storage]
+	#[pallet::getter(fn voted_count)]
+	pub type VotedCount<T: Config> = StorageMap<_, Twox64Concat, ElectionId, u32, ValueQuery>;
+
+	/// A registered voter's participation in a single election, created when they vote.
 	#[pallet::storage]
 	#[pallet::getter(fn voter_account)]
-	pub type AccountToVoterInfo<T: Config> =
-		StorageMap<_, Blake2_128Concat, T::AccountId, VoterInfo<T>, OptionQuery>;
+	pub type AccountToVoterInfo<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ElectionId,
+		Blake2_128Concat,
+		T::AccountId,
+		VoterInfo<T>,
+		OptionQuery,
+	>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn candidate_account)]
-	pub type AccountToCandidateInfo<T: Config> =
-		StorageMap<_, Blake2_128Concat, T::AccountId, CandidateInfo, OptionQuery>;
+	pub type AccountToCandidateInfo<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ElectionId,
+		Blake2_128Concat,
+		T::AccountId,
+		CandidateInfo<T>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_election_id)]
+	pub type NextElectionId<T: Config> = StorageValue<_, ElectionId, ValueQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn get_election)]
-	pub type ElectionConfig<T: Config> = StorageValue<_, ElectionInfo<T>, OptionQuery>;
+	pub type ElectionConfig<T: Config> =
+		StorageMap<_, Twox64Concat, ElectionId, ElectionInfo<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn candidate_count)]
+	pub type CandidateCount<T: Config> = StorageMap<_, Twox64Concat, ElectionId, u32, ValueQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn max_votes_candidate)]
 	pub type MaxVoteCandidate<T: Config> =
-		StorageValue<_, BoundedVec<T::AccountId, ConstU32<100>>, OptionQuery>;
+		StorageMap<_, Twox64Concat, ElectionId, CandidateList<T>, OptionQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn max_votes)]
-	pub type MaxVote<T: Config> = StorageValue<_, u32, ValueQuery>;
+	pub type MaxVote<T: Config> = StorageMap<_, Twox64Concat, ElectionId, VoteWeight, ValueQuery>;
+
+	/// Commitments of a commit-reveal election that have not been revealed (yet).
+	#[pallet::storage]
+	#[pallet::getter(fn unrevealed_votes)]
+	pub type UnrevealedVotes<T: Config> = StorageMap<_, Twox64Concat, ElectionId, u32, ValueQuery>;
+
+	/// Ranked ballots of an instant-runoff election, most preferred candidate first.
+	#[pallet::storage]
+	#[pallet::getter(fn ranked_ballot)]
+	pub type RankedBallots<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ElectionId,
+		Blake2_128Concat,
+		T::AccountId,
+		CandidateList<T>,
+		OptionQuery,
+	>;
+
+	/// Candidates each voter approved of in an approval election.
+	#[pallet::storage]
+	#[pallet::getter(fn approval_ballot)]
+	pub type ApprovalBallots<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ElectionId,
+		Blake2_128Concat,
+		T::AccountId,
+		CandidateList<T>,
+		OptionQuery,
+	>;
+
+	/// Scores each voter gave in a score or STAR election. Unlisted candidates scored 0.
+	#[pallet::storage]
+	#[pallet::getter(fn score_ballot)]
+	pub type ScoreBallots<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ElectionId,
+		Blake2_128Concat,
+		T::AccountId,
+		ScoreList<T>,
+		OptionQuery,
+	>;
+
+	/// The two finalists of a STAR runoff, each with the number of ballots that preferred them.
+	#[pallet::storage]
+	#[pallet::getter(fn star_finalists)]
+	pub type StarFinalists<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		ElectionId,
+		BoundedVec<(T::AccountId, u32), ConstU32<2>>,
+		OptionQuery,
+	>;
+
+	/// Round-by-round log of an STV count, starting at round 0.
+	#[pallet::storage]
+	#[pallet::getter(fn stv_round)]
+	pub type StvRounds<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ElectionId,
+		Twox64Concat,
+		u32,
+		StvRoundInfo<T>,
+		OptionQuery,
+	>;
+
+	/// Per-round counts of an instant-runoff tally, starting at round 0.
+	#[pallet::storage]
+	#[pallet::getter(fn irv_round)]
+	pub type IrvRounds<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ElectionId,
+		Twox64Concat,
+		u32,
+		BoundedVec<(T::AccountId, u32), MaxCandidates>,
+		OptionQuery,
+	>;
+
+	/// Votes each voter has bought for each candidate in a quadratic election.
+	#[pallet::storage]
+	#[pallet::getter(fn quadratic_ballot)]
+	pub type QuadraticBallots<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ElectionId,
+		Blake2_128Concat,
+		T::AccountId,
+		ScoreList<T>,
+		OptionQuery,
+	>;
+
+	/// Balances locked by each voter's token-weighted and conviction votes, per election. The
+	/// account's lock is the largest amount still held here.
+	#[pallet::storage]
+	#[pallet::getter(fn conviction_lock)]
+	pub type ConvictionLocks<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Twox64Concat,
+		ElectionId,
+		ConvictionLock<T>,
+		OptionQuery,
+	>;
+
+	/// Borda points of each candidate, written when a Borda election is tallied.
+	#[pallet::storage]
+	#[pallet::getter(fn borda_score)]
+	pub type BordaScores<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ElectionId,
+		Blake2_128Concat,
+		T::AccountId,
+		u32,
+		ValueQuery,
+	>;
+
+	/// Pairwise preferences of a Schulze election: the number of ballots ranking the first
+	/// candidate above the second.
+	#[pallet::storage]
+	#[pallet::getter(fn pairwise_preference)]
+	pub type PairwisePreferences<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ElectionId,
+		Blake2_128Concat,
+		(T::AccountId, T::AccountId),
+		u32,
+		ValueQuery,
+	>;
+
+	/// Candidate preferred over every other candidate by a majority of ranked ballots, if any.
+	#[pallet::storage]
+	#[pallet::getter(fn condorcet_winner)]
+	pub type CondorcetWinner<T: Config> =
+		StorageMap<_, Twox64Concat, ElectionId, T::AccountId, OptionQuery>;
+
+	/// How the tie for the win of an election was settled, for elections that ended in one.
+	#[pallet::storage]
+	#[pallet::getter(fn tie_breaks)]
+	pub type TieBreaks<T: Config> =
+		StorageMap<_, Twox64Concat, ElectionId, TieBreakOutcome<T>, OptionQuery>;
+
+	/// The outcome of every counted election, kept for good. Unlike `MaxVoteCandidate`, it
+	/// outlives the election's ballots and candidate records.
+	#[pallet::storage]
+	#[pallet::getter(fn election_result)]
+	pub type ElectionResults<T: Config> =
+		StorageMap<_, Twox64Concat, ElectionId, ElectionResult<T>, OptionQuery>;
+
+	/// Closed elections whose ballots and candidate records are still to be removed.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_purge)]
+	pub type PendingPurges<T: Config> =
+		StorageMap<_, Twox64Concat, ElectionId, PurgeProgress, OptionQuery>;
+
+	/// Final status of every election that was finalized, cancelled or found invalid.
+	#[pallet::storage]
+	pub type ClosedElections<T: Config> =
+		StorageMap<_, Twox64Concat, ElectionId, ElectionStatus, OptionQuery>;
+
+	/// The runoff scheduled to settle an election, by a tie-break or for want of a majority.
+	#[pallet::storage]
+	#[pallet::getter(fn runoff_election)]
+	pub type RunoffElection<T: Config> =
+		StorageMap<_, Twox64Concat, ElectionId, ElectionId, OptionQuery>;
+
+	/// The election a runoff was scheduled to settle.
+	#[pallet::storage]
+	#[pallet::getter(fn runoff_of)]
+	pub type RunoffOf<T: Config> = StorageMap<_, Twox64Concat, ElectionId, ElectionId, OptionQuery>;
+
+	/// Elections `on_initialize` tallies at a block: those whose voting, and any reveal window,
+	/// closed the block before.
+	#[pallet::storage]
+	#[pallet::getter(fn elections_to_finalize)]
+	pub type ElectionsToFinalize<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<ElectionId, T::MaxElectionsPerBlock>,
+		ValueQuery,
+	>;
 
 	/* ---------------------------------- Event --------------------------------- */
 
@@ -129,16 +1102,171 @@ pub mod pallet {
 	pub enum Event<T: Config> {
 		// A Voter has registered
 		RegisterVoter,
+		// The election authority has put voters on the roll, counting only those not already on it
+		VotersAdded {
+			count: u32,
+		},
+		// The election authority has taken a voter off the roll
+		VoterRemoved {
+			who: T::AccountId,
+		},
+		// Voter has enrolled in an election's registration phase
+		VoterEnrolled {
+			election_id: ElectionId,
+			who: T::AccountId,
+		},
 		// A Candidate has registered
-		RegisterCandidate,
+		RegisterCandidate {
+			election_id: ElectionId,
+		},
+		// A Candidate has changed their name, affiliation or manifesto
+		CandidateProfileUpdated {
+			election_id: ElectionId,
+			candidate: T::AccountId,
+		},
 		// Voter has voted successfully
-		VoteSuccess,
+		VoteSuccess {
+			election_id: ElectionId,
+		},
 		// Get the Current Vote Count of a Candidate
 		RecieveVoteCount,
-		// Election timings has been configured
-		ElectionConfigured,
+		// A new election has been created with its timings configured
+		ElectionCreated {
+			election_id: ElectionId,
+		},
 		// Winner for the election have been stored in a storage
-		WinnerVecStored,
+		WinnerVecStored {
+			election_id: ElectionId,
+		},
+		// The election was tallied automatically once it closed
+		ElectionFinalized {
+			election_id: ElectionId,
+		},
+		// Tallying the election automatically failed. `winner` can still be called by hand
+		ElectionFinalizationFailed {
+			election_id: ElectionId,
+			error: DispatchError,
+		},
+		// The election was too heavy to tally automatically and is left for `winner`
+		ElectionFinalizationDeferred {
+			election_id: ElectionId,
+		},
+		// Too few voters took part, so the election is invalid and has no winner
+		QuorumNotReached {
+			election_id: ElectionId,
+			turnout: Turnout,
+		},
+		// No candidate got the election's majority, so the two leading candidates go to `runoff`
+		MajorityNotReached {
+			election_id: ElectionId,
+			runoff: ElectionId,
+		},
+		// The election ended in a tie, settled by `policy`
+		TieBroken {
+			election_id: ElectionId,
+			policy: TieBreak,
+			winner: Option<T::AccountId>,
+			runoff: Option<ElectionId>,
+		},
+		// Voter has committed to a sealed ballot
+		VoteCommitted {
+			election_id: ElectionId,
+		},
+		// Voter has opened their sealed ballot and it was counted
+		VoteRevealed {
+			election_id: ElectionId,
+		},
+		// Commitments that were never revealed, and so were not counted
+		UnrevealedVotesReported {
+			election_id: ElectionId,
+			count: u32,
+		},
+		// Voter has cast a ranked ballot
+		RankedVoteSuccess {
+			election_id: ElectionId,
+		},
+		// Voter has cast an approval ballot
+		ApprovalVoteSuccess {
+			election_id: ElectionId,
+		},
+		// Voter has cast a score ballot
+		ScoreVoteSuccess {
+			election_id: ElectionId,
+		},
+		// Voter has bought votes for a candidate in a quadratic election
+		QuadraticVoteSuccess {
+			election_id: ElectionId,
+			candidate: T::AccountId,
+			votes: u32,
+		},
+		// Voter has cast a conviction vote, locking their balance until `until`
+		ConvictionVoteSuccess {
+			election_id: ElectionId,
+			conviction: Conviction,
+			until: T::BlockNumber,
+		},
+		// A candidate reached `CandidacyBondThreshold` and got their bond back
+		BondReturned {
+			election_id: ElectionId,
+			candidate: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		// A candidate fell short of `CandidacyBondThreshold` and forfeited their bond
+		BondForfeited {
+			election_id: ElectionId,
+			candidate: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		// The balance a conviction vote locked has been released
+		FundsUnlocked {
+			who: T::AccountId,
+			election_id: ElectionId,
+		},
+		// The election authority called the election off
+		ElectionCancelled {
+			election_id: ElectionId,
+		},
+		// The election authority moved the end of voting to `end`
+		VotingExtended {
+			election_id: ElectionId,
+			end: T::BlockNumber,
+		},
+		// The election authority moved voting to run from `start` to `end`
+		ElectionPostponed {
+			election_id: ElectionId,
+			start: T::BlockNumber,
+			end: T::BlockNumber,
+		},
+		// Voter has handed their vote to another voter
+		Delegated {
+			election_id: ElectionId,
+			who: T::AccountId,
+			to: T::AccountId,
+		},
+		// Voter has taken back the vote they delegated
+		Undelegated {
+			election_id: ElectionId,
+			who: T::AccountId,
+		},
+		// Voter has moved their vote to another candidate
+		VoteChanged {
+			election_id: ElectionId,
+			who: T::AccountId,
+		},
+		// Voter has withdrawn their vote
+		VoteRevoked {
+			election_id: ElectionId,
+			who: T::AccountId,
+		},
+		// A ranked election has a candidate who beats every other candidate head-to-head
+		CondorcetWinnerFound {
+			election_id: ElectionId,
+			candidate: T::AccountId,
+		},
+		// Every ballot and candidate record of a closed election has been removed
+		ElectionPurged {
+			election_id: ElectionId,
+		},
 	}
 
 	/* ---------------------------------- Error --------------------------------- */
@@ -148,11 +1276,11 @@ pub mod pallet {
 		AlreadyVoted,
 		// AccountId already registered as Candidate
 		AlreadyRegistered,
-		// Election timings are already set
-		AlreadyConfiguredElection,
+		// No more election ids can be handed out
+		ElectionIdOverflow,
 		// AccountId is not registered as Voter or Candidate
 		NotRegistered,
-		// Election timing is not set
+		// No election exists for the given ElectionId
 		ElectionNotConfigured,
 		// Election has not yet started
 		ElectionNotStarted,
@@ -164,6 +1292,160 @@ pub mod pallet {
 		MaxCandidatesExceed,
 		// When Election start time > end time
 		ElectionTimeIllogical,
+		// Election has already started
+		ElectionStarted,
+		// Candidate display name is empty
+		EmptyCandidateName,
+		// Election only accepts sealed ballots through `commit_vote`
+		SecretBallotRequired,
+		// Election does not use commit-reveal ballots
+		SecretBallotNotEnabled,
+		// Reveal window opens after the voting window ends
+		RevealNotStarted,
+		// Reveal window has ended
+		RevealEnded,
+		// Reveal window has not ended
+		RevealNotEnded,
+		// Voter has no sealed ballot to reveal
+		NoCommitment,
+		// Sealed ballot has already been revealed
+		AlreadyRevealed,
+		// Revealed candidate and salt do not match the commitment
+		CommitmentMismatch,
+		// Ballot does not match the election's voting method
+		WrongBallotType,
+		// Sealed ballots are only available for plurality elections
+		SecretBallotNotSupported,
+		// Ballot lists no candidates
+		EmptyBallot,
+		// Ballot lists the same candidate more than once
+		DuplicateCandidate,
+		// Score and STAR elections need a `max_score` above zero
+		InvalidScoreScale,
+		// Ballot gives a candidate more than the election's `max_score`
+		ScoreOutOfRange,
+		// STV elections need between 1 and 100 seats
+		InvalidSeatCount,
+		// The runtime's `TallyMethods` cannot count this voting method
+		UnsupportedTallyMethod,
+		// Quadratic elections need a credit budget above 0
+		InvalidCreditBudget,
+		// Buying these votes would take the voter over the election's credit budget
+		InsufficientCredits,
+		// Token weighting is only available to plurality elections
+		WeightingNotSupported,
+		// The voter has no balance to weigh their ballot by
+		NoVotingWeight,
+		// Conviction votes need an open-ballot election weighted by free balance that takes them
+		ConvictionNotSupported,
+		// The voter has no conviction lock in this election
+		NoLock,
+		// The conviction lock has not run out yet
+		LockNotExpired,
+		// Votes can only be delegated in plurality elections with open ballots
+		DelegationNotSupported,
+		// Delegating to this voter would lead back to the delegator
+		DelegationCycle,
+		// Delegating would make a chain longer than `MaxDelegationDepth`
+		DelegationTooDeep,
+		// The voter has already delegated their vote in this election
+		AlreadyDelegating,
+		// The voter has not delegated their vote in this election
+		NotDelegating,
+		// The voter has no counted vote in this election to change or revoke
+		NotVoted,
+		// The account is not on the voter roll
+		NotOnVoterRoll,
+		// The candidate cannot afford the candidacy bond
+		InsufficientBond,
+		// The election's nomination phase has not started
+		NominationNotStarted,
+		// The election's nomination phase has ended
+		NominationEnded,
+		// The election has no registration phase, so voters need not enroll
+		RegistrationNotScheduled,
+		// The election's registration phase has not started
+		RegistrationNotStarted,
+		// The election's registration phase has ended
+		RegistrationEnded,
+		// `MaxElectionsPerBlock` elections already close in the same block
+		TooManyElectionsEnding,
+		// STV elections fill several seats, so their ties cannot be broken
+		TieBreakNotSupported,
+		// Only plurality elections can require a majority
+		MajorityNotSupported,
+		// The election has been cancelled
+		ElectionCancelled,
+		// The election has already been finalized, cancelled or found invalid
+		ElectionClosed,
+		// The ballot count given to `winner` is below the number of ballots cast
+		BallotCountTooLow,
+		// The election is still open, so its records cannot be purged
+		ElectionNotClosed,
+		// The election's ballots and candidate records have already been removed
+		ElectionAlreadyPurged,
+		// The election would close before the next block, so it would never be finalized
+		ElectionEndPassed,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Tally every election that closed in the previous block, unless `winner` already has.
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let due = ElectionsToFinalize::<T>::take(now);
+			let mut weight = T::DbWeight::get().reads_writes(1, 1);
+			let mut budget = T::MaxFinalizationWeight::get();
+
+			for election_id in due {
+				weight = weight.saturating_add(T::DbWeight::get().reads(2));
+				if ClosedElections::<T>::contains_key(election_id) {
+					continue;
+				}
+				let Some(election) = ElectionConfig::<T>::get(election_id) else { continue };
+				let tally = Self::tally_weight(election_id, &election);
+				let Some(left) = budget.checked_sub(&tally) else {
+					Self::deposit_event(Event::ElectionFinalizationDeferred { election_id });
+					continue;
+				};
+				budget = left;
+				weight = weight.saturating_add(tally);
+
+				// A tally that fails part way must not leave half its results behind
+				let outcome = with_transaction(|| match Self::finalize(election_id, &election) {
+					Ok(()) => TransactionOutcome::Commit(Ok(())),
+					Err(error) => TransactionOutcome::Rollback(Err(error)),
+				});
+				match outcome {
+					Ok(()) => Self::deposit_event(Event::ElectionFinalized { election_id }),
+					Err(error) => Self::deposit_event(Event::ElectionFinalizationFailed {
+						election_id,
+						error,
+					}),
+				}
+			}
+
+			weight
+		}
+
+		/// Spend the block's spare weight removing the records of a closed election.
+		fn on_idle(_now: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			let lookup = T::DbWeight::get().reads(1);
+			let base = T::WeightInfo::purge_election(0).saturating_add(lookup);
+			let per_record =
+				T::WeightInfo::purge_election(1).saturating_sub(T::WeightInfo::purge_election(0));
+			let Some(spare) = remaining_weight.checked_sub(&base) else { return Weight::zero() };
+
+			let fits = |budget: u64, cost: u64| budget.checked_div(cost).unwrap_or(u64::MAX);
+			let limit = fits(spare.ref_time(), per_record.ref_time())
+				.min(fits(spare.proof_size(), per_record.proof_size()));
+			if limit == 0 {
+				return Weight::zero();
+			}
+			let Some(election_id) = PendingPurges::<T>::iter_keys().next() else { return lookup };
+
+			let removed = Self::purge_records(election_id, limit.unique_saturated_into());
+			T::WeightInfo::purge_election(removed).saturating_add(lookup)
+		}
 	}
 
 	/* ---------------------------------- Extrinsics ---------------------------------- */
@@ -175,11 +1457,13 @@ pub mod pallet {
 		pub fn register_voter(origin: OriginFor<T>) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 
-			let is_voter = <AccountToVoterInfo<T>>::contains_key(sender.clone());
+			let is_voter = Self::is_registered_voter(&sender);
 			ensure!(!is_voter, Error::<T>::AlreadyRegistered);
 
-			let new_voter = VoterInfo::<T>::new();
-			<AccountToVoterInfo<T>>::insert(sender, new_voter);
+			if !VoterRoll::<T>::contains_key(&sender) {
+				OpenElectorateCount::<T>::mutate(|count| *count = count.saturating_add(1));
+			}
+			<RegisteredVoters<T>>::insert(sender, ());
 
 			Self::deposit_event(Event::RegisterVoter);
 			Ok(())
@@ -188,111 +1472,1436 @@ pub mod pallet {
 		#[pallet::call_index(1)]
 		#[pallet::weight(T::WeightInfo::register_candidate())]
 		// #[pallet::weight(0)]
-		pub fn register_candidate(origin: OriginFor<T>) -> DispatchResult {
+		pub fn register_candidate(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			name: ProfileText,
+			party: Option<ProfileText>,
+			manifesto_hash: T::Hash,
+		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 
-			let is_candidate = AccountToCandidateInfo::<T>::contains_key(sender.clone());
+			ElectionInfo::<T>::ensure_nomination_progress(election_id)?;
+
+			let is_candidate =
+				AccountToCandidateInfo::<T>::contains_key(election_id, sender.clone());
 			ensure!(!is_candidate, Error::<T>::AlreadyRegistered);
 
-			let new_candidate = CandidateInfo::new();
-			AccountToCandidateInfo::<T>::insert(sender, new_candidate);
+			ensure!(!name.is_empty(), Error::<T>::EmptyCandidateName);
+			let candidate_count = CandidateCount::<T>::get(election_id);
+			ensure!(candidate_count < MaxCandidates::get(), Error::<T>::MaxCandidatesExceed);
+
+			let bond = T::CandidacyBond::get();
+			T::Currency::reserve(&sender, bond).map_err(|_| Error::<T>::InsufficientBond)?;
 
-			Self::deposit_event(Event::RegisterCandidate);
+			let new_candidate = CandidateInfo::<T>::new(name, party, manifesto_hash)
+				.with_bond(bond)
+				.with_registration_index(candidate_count);
+			AccountToCandidateInfo::<T>::insert(election_id, sender, new_candidate);
+			CandidateCount::<T>::insert(election_id, candidate_count + 1);
+
+			Self::deposit_event(Event::RegisterCandidate { election_id });
 			Ok(())
 		}
 
 		#[pallet::call_index(2)]
-		#[pallet::weight(T::WeightInfo::config_election())]
+		#[pallet::weight(T::WeightInfo::create_election())]
 		// #[pallet::weight(0)]
-		pub fn config_election(
+		pub fn create_election(
 			origin: OriginFor<T>,
 			start: T::BlockNumber,
 			end: T::BlockNumber,
+			reveal_end: Option<T::BlockNumber>,
+			settings: ElectionSettings,
+			phases: ElectionPhases<T::BlockNumber>,
 		) -> DispatchResult {
 			ensure_root(origin)?;
 
-			let is_configured_election = ElectionConfig::<T>::exists();
-			ensure!(!is_configured_election, Error::<T>::AlreadyConfiguredElection);
 			ensure!(start < end, Error::<T>::ElectionTimeIllogical);
-
-			let election = ElectionInfo::<T>::set(start, end);
-
-			ElectionConfig::<T>::put(&election);
-
-			Self::deposit_event(Event::ElectionConfigured);
+			for window in [phases.nomination, phases.registration].into_iter().flatten() {
+				ensure!(
+					window.start <= window.end && window.end < start,
+					Error::<T>::ElectionTimeIllogical
+				);
+			}
+			ensure!(T::TallyMethods::supports(settings.method), Error::<T>::UnsupportedTallyMethod);
+			if let Some(reveal_end) = reveal_end {
+				ensure!(end < reveal_end, Error::<T>::ElectionTimeIllogical);
+				ensure!(
+					settings.method == VotingMethod::Plurality,
+					Error::<T>::SecretBallotNotSupported
+				);
+			}
+			if settings.method.is_scored() {
+				ensure!(settings.max_score > 0, Error::<T>::InvalidScoreScale);
+			}
+			if settings.weighting != VoteWeighting::OnePerAccount {
+				ensure!(
+					settings.method == VotingMethod::Plurality,
+					Error::<T>::WeightingNotSupported
+				);
+			}
+			if settings.conviction {
+				ensure!(
+					settings.weighting == VoteWeighting::FreeBalance && reveal_end.is_none(),
+					Error::<T>::ConvictionNotSupported
+				);
+			}
+			if settings.method == VotingMethod::Quadratic {
+				ensure!(settings.credits > 0, Error::<T>::InvalidCreditBudget);
+			}
+			if let Some(majority) = settings.majority {
+				ensure!(
+					settings.method == VotingMethod::Plurality,
+					Error::<T>::MajorityNotSupported
+				);
+				ensure!(majority.runoff_duration > 0, Error::<T>::ElectionTimeIllogical);
+			}
+			if settings.method == VotingMethod::Stv {
+				ensure!(
+					settings.seats > 0 && settings.seats <= MaxCandidates::get(),
+					Error::<T>::InvalidSeatCount
+				);
+				ensure!(settings.tie_break == TieBreak::KeepAll, Error::<T>::TieBreakNotSupported);
+			}
+
+			let election_id = NextElectionId::<T>::get();
+			let next_id = election_id.checked_add(1).ok_or(Error::<T>::ElectionIdOverflow)?;
+
+			let election =
+				ElectionInfo::<T>::set(start, end, reveal_end, settings).with_phases(phases);
+			ensure!(
+				election.finalize_at() > <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::ElectionEndPassed
+			);
+
+			ElectionsToFinalize::<T>::try_append(election.finalize_at(), election_id)
+				.map_err(|_| Error::<T>::TooManyElectionsEnding)?;
+
+			ElectionConfig::<T>::insert(election_id, &election);
+			NextElectionId::<T>::put(next_id);
+
+			Self::deposit_event(Event::ElectionCreated { election_id });
 			Ok(())
 		}
 
 		#[pallet::call_index(3)]
 		#[pallet::weight(T::WeightInfo::give_vote())]
 		// #[pallet::weight(0)]
-		pub fn give_vote(origin: OriginFor<T>, to_vote_for: T::AccountId) -> DispatchResult {
+		pub fn give_vote(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			to_vote_for: T::AccountId,
+		) -> DispatchResult {
 			let voter_account = ensure_signed(origin)?;
 
-			ElectionInfo::<T>::ensure_election_progress()?;
+			ElectionInfo::<T>::ensure_election_progress(election_id)?;
+			let election = ElectionConfig::<T>::get(election_id).unwrap();
+			ensure!(!election.is_commit_reveal(), Error::<T>::SecretBallotRequired);
+			ensure!(election.method() == VotingMethod::Plurality, Error::<T>::WrongBallotType);
 
-			let is_voter = <AccountToVoterInfo<T>>::contains_key(voter_account.clone());
-			ensure!(is_voter, Error::<T>::NotRegistered);
+			Self::ensure_can_vote(election_id, &voter_account)?;
 
-			let is_candidate = <AccountToCandidateInfo<T>>::contains_key(to_vote_for.clone());
+			let is_candidate =
+				<AccountToCandidateInfo<T>>::contains_key(election_id, to_vote_for.clone());
 			ensure!(is_candidate, Error::<T>::NotRegistered);
+			let weight = Self::ballot_weight(election_id, &election, &voter_account)?;
+			let previous = Self::withdraw_delegation(election_id, &voter_account)?;
 
-			let voterinfo =
-				<AccountToVoterInfo<T>>::get(&voter_account).clone().expect("No VoterInfo");
-			ensure!(!voterinfo.vote_status, Error::<T>::AlreadyVoted);
+			VotedCount::<T>::mutate(election_id, |count| *count += 1);
+			AccountToVoterInfo::<T>::insert(
+				election_id,
+				voter_account.clone(),
+				VoterInfo::<T>::set(true, to_vote_for.clone())
+					.with_weight(weight)
+					.with_delegations_of(&previous),
+			);
 
-			AccountToCandidateInfo::<T>::mutate(to_vote_for.clone(), |val| {
-				val.as_mut().unwrap().vote_count += 1
-			});
+			// The ballot also carries everyone delegating to this voter
+			Self::count_vote(
+				election_id,
+				&to_vote_for,
+				weight.saturating_add(previous.delegated()),
+			);
+
+			Self::deposit_event(Event::VoteSuccess { election_id });
+			Ok(())
+		}
 
-			AccountToVoterInfo::<T>::mutate(voter_account.clone(), |voter| {
-				voter.as_mut().unwrap().vote_status = true
+		/// Count an election whose voting, and any reveal window, has closed. `ballots` must be
+		/// at least the election's `voted_count`, and bounds the weight charged up front.
+		#[pallet::call_index(4)]
+		#[pallet::weight(
+			T::WeightInfo::winner(MaxCandidates::get())
+				.max(T::WeightInfo::winner_ranked(MaxCandidates::get(), *ballots))
+				.max(T::WeightInfo::winner_stv(MaxCandidates::get(), *ballots))
+		)]
+		pub fn winner(
+			_origin: OriginFor<T>,
+			election_id: ElectionId,
+			ballots: u32,
+		) -> DispatchResultWithPostInfo {
+			//Election was configured and has ended
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			ensure!(
+				ElectionConfig::<T>::contains_key(election_id),
+				Error::<T>::ElectionNotConfigured
+			);
+			ElectionInfo::<T>::ensure_not_cancelled(election_id)?;
+			ensure!(!ClosedElections::<T>::contains_key(election_id), Error::<T>::ElectionClosed);
+			let election = ElectionConfig::<T>::get(election_id).unwrap();
+			ensure!(block_number > election.end_block.unwrap(), Error::<T>::ElectionNotEnded);
+			if let Some(reveal_end) = election.reveal_end {
+				ensure!(block_number > reveal_end, Error::<T>::RevealNotEnded);
+			}
+			ensure!(VotedCount::<T>::get(election_id) <= ballots, Error::<T>::BallotCountTooLow);
+
+			Self::finalize(election_id, &election)?;
+			Ok(Some(Self::tally_weight(election_id, &election)).into())
+		}
+
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::update_candidate_profile())]
+		pub fn update_candidate_profile(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			name: ProfileText,
+			party: Option<ProfileText>,
+			manifesto_hash: T::Hash,
+		) -> DispatchResult {
+			let candidate = ensure_signed(origin)?;
+
+			ElectionInfo::<T>::ensure_election_not_started(election_id)?;
+
+			let is_candidate =
+				AccountToCandidateInfo::<T>::contains_key(election_id, candidate.clone());
+			ensure!(is_candidate, Error::<T>::NotRegistered);
+			ensure!(!name.is_empty(), Error::<T>::EmptyCandidateName);
+
+			AccountToCandidateInfo::<T>::mutate(election_id, candidate.clone(), |val| {
+				let info = val.as_mut().unwrap();
+				info.name = name;
+				info.party = party;
+				info.manifesto_hash = manifesto_hash;
 			});
 
-			AccountToVoterInfo::<T>::mutate(voter_account.clone(), |voter| {
-				voter.as_mut().unwrap().voted_for = Some(to_vote_for.clone())
+			Self::deposit_event(Event::CandidateProfileUpdated { election_id, candidate });
+			Ok(())
+		}
+
+		/// Submit `hash(candidate, salt)` during the voting window of a commit-reveal election.
+		/// The commitment can be built with [`Pallet::ballot_commitment`].
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::commit_vote())]
+		pub fn commit_vote(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			commitment: T::Hash,
+		) -> DispatchResult {
+			let voter_account = ensure_signed(origin)?;
+
+			ElectionInfo::<T>::ensure_election_progress(election_id)?;
+			let election = ElectionConfig::<T>::get(election_id).unwrap();
+			ensure!(election.is_commit_reveal(), Error::<T>::SecretBallotNotEnabled);
+
+			Self::ensure_can_vote(election_id, &voter_account)?;
+			let weight = Self::ballot_weight(election_id, &election, &voter_account)?;
+
+			AccountToVoterInfo::<T>::insert(
+				election_id,
+				voter_account,
+				VoterInfo::<T>::committed(commitment).with_weight(weight),
+			);
+			VotedCount::<T>::mutate(election_id, |count| *count += 1);
+			UnrevealedVotes::<T>::mutate(election_id, |count| *count += 1);
+
+			Self::deposit_event(Event::VoteCommitted { election_id });
+			Ok(())
+		}
+
+		/// Open a sealed ballot after `end_block`. Only revealed ballots are tallied.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::reveal_vote())]
+		pub fn reveal_vote(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			candidate: T::AccountId,
+			salt: [u8; 32],
+		) -> DispatchResult {
+			let voter_account = ensure_signed(origin)?;
+
+			ElectionInfo::<T>::ensure_reveal_progress(election_id)?;
+
+			let voterinfo = <AccountToVoterInfo<T>>::get(election_id, &voter_account)
+				.ok_or(Error::<T>::NoCommitment)?;
+			let commitment = voterinfo.commitment.ok_or(Error::<T>::NoCommitment)?;
+			ensure!(voterinfo.voted_for.is_none(), Error::<T>::AlreadyRevealed);
+			ensure!(
+				Self::ballot_commitment(election_id, &voter_account, &candidate, &salt)
+					== commitment,
+				Error::<T>::CommitmentMismatch
+			);
+
+			let is_candidate =
+				<AccountToCandidateInfo<T>>::contains_key(election_id, candidate.clone());
+			ensure!(is_candidate, Error::<T>::NotRegistered);
+
+			AccountToVoterInfo::<T>::mutate(election_id, voter_account, |voter| {
+				voter.as_mut().unwrap().voted_for = Some(candidate.clone())
 			});
+			UnrevealedVotes::<T>::mutate(election_id, |count| *count -= 1);
 
-			// Max Votes
-			let max_votes = MaxVote::<T>::get();
-			let candidate_votes =
-				AccountToCandidateInfo::<T>::get(&to_vote_for).unwrap().vote_count;
-			if max_votes < candidate_votes {
-				MaxVote::<T>::put(candidate_votes);
+			Self::count_vote(election_id, &candidate, voterinfo.weight());
+
+			Self::deposit_event(Event::VoteRevealed { election_id });
+			Ok(())
+		}
+
+		/// Cast a ranked ballot in an instant-runoff or STV election, most preferred candidate
+		/// first.
+		/// Candidates left off the ballot are never counted for this voter.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::give_ranked_vote())]
+		pub fn give_ranked_vote(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			ranking: CandidateList<T>,
+		) -> DispatchResult {
+			let voter_account = ensure_signed(origin)?;
+
+			ElectionInfo::<T>::ensure_election_progress(election_id)?;
+			let election = ElectionConfig::<T>::get(election_id).unwrap();
+			ensure!(election.method().is_ranked(), Error::<T>::WrongBallotType);
+
+			Self::ensure_can_vote(election_id, &voter_account)?;
+			Self::ensure_valid_candidate_list(election_id, &ranking)?;
+
+			let first_choice = ranking[0].clone();
+			AccountToVoterInfo::<T>::insert(
+				election_id,
+				voter_account.clone(),
+				VoterInfo::<T>::set(true, first_choice.clone()),
+			);
+			VotedCount::<T>::mutate(election_id, |count| *count += 1);
+			RankedBallots::<T>::insert(election_id, voter_account, ranking);
+
+			// `vote_count` holds first preferences, the runoff itself happens in `winner`
+			Self::count_vote(election_id, &first_choice, 1);
+
+			Self::deposit_event(Event::RankedVoteSuccess { election_id });
+			Ok(())
+		}
+
+		/// Cast an approval ballot: every listed candidate gets one vote.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::give_approval_vote())]
+		pub fn give_approval_vote(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			approved: CandidateList<T>,
+		) -> DispatchResult {
+			let voter_account = ensure_signed(origin)?;
+
+			ElectionInfo::<T>::ensure_election_progress(election_id)?;
+			let election = ElectionConfig::<T>::get(election_id).unwrap();
+			ensure!(election.method() == VotingMethod::Approval, Error::<T>::WrongBallotType);
+
+			Self::ensure_can_vote(election_id, &voter_account)?;
+			Self::ensure_valid_candidate_list(election_id, &approved)?;
+
+			for candidate in approved.iter() {
+				Self::count_vote(election_id, candidate, 1);
 			}
 
-			Self::deposit_event(Event::VoteSuccess);
+			AccountToVoterInfo::<T>::insert(
+				election_id,
+				voter_account.clone(),
+				VoterInfo::<T>::multi_candidate(),
+			);
+			VotedCount::<T>::mutate(election_id, |count| *count += 1);
+			ApprovalBallots::<T>::insert(election_id, voter_account, approved);
+
+			Self::deposit_event(Event::ApprovalVoteSuccess { election_id });
 			Ok(())
 		}
 
-		#[pallet::call_index(4)]
-		#[pallet::weight(T::WeightInfo::winner())]
-		// #[pallet::weight(0)]
-		pub fn winner(_origin: OriginFor<T>) -> DispatchResult {
-			//Election was configured and has ended
+		/// Cast a score ballot, giving each listed candidate a score from 0 to the election's
+		/// `max_score`. Candidates left off the ballot are scored 0.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::give_score_vote())]
+		pub fn give_score_vote(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			scores: ScoreList<T>,
+		) -> DispatchResult {
+			let voter_account = ensure_signed(origin)?;
+
+			ElectionInfo::<T>::ensure_election_progress(election_id)?;
+			let election = ElectionConfig::<T>::get(election_id).unwrap();
+			ensure!(election.method().is_scored(), Error::<T>::WrongBallotType);
+
+			Self::ensure_can_vote(election_id, &voter_account)?;
+			let candidates: Vec<T::AccountId> =
+				scores.iter().map(|(candidate, _)| candidate.clone()).collect();
+			Self::ensure_valid_candidate_list(election_id, &candidates)?;
+			let max_score = election.max_score();
+			ensure!(
+				scores.iter().all(|(_, score)| *score <= max_score),
+				Error::<T>::ScoreOutOfRange
+			);
+
+			for (candidate, score) in scores.iter() {
+				Self::count_vote(election_id, candidate, (*score).into());
+			}
+
+			AccountToVoterInfo::<T>::insert(
+				election_id,
+				voter_account.clone(),
+				VoterInfo::<T>::multi_candidate(),
+			);
+			VotedCount::<T>::mutate(election_id, |count| *count += 1);
+			ScoreBallots::<T>::insert(election_id, voter_account, scores);
+
+			Self::deposit_event(Event::ScoreVoteSuccess { election_id });
+			Ok(())
+		}
+
+		/// Buy `votes` more votes for `candidate` in a quadratic election.
+		/// A voter's total votes for one candidate cost the square of that total in credits, so
+		/// going from `a` to `a + votes` costs `(a + votes)² - a²`. Voters can keep buying votes
+		/// until the election's credit budget is spent.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::give_votes())]
+		pub fn give_votes(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			candidate: T::AccountId,
+			votes: u32,
+		) -> DispatchResult {
+			let voter_account = ensure_signed(origin)?;
+
+			ElectionInfo::<T>::ensure_election_progress(election_id)?;
+			let election = ElectionConfig::<T>::get(election_id).unwrap();
+			ensure!(election.method() == VotingMethod::Quadratic, Error::<T>::WrongBallotType);
+
+			ensure!(
+				Self::is_eligible_voter(election_id, &voter_account),
+				Error::<T>::NotRegistered
+			);
+			ensure!(votes > 0, Error::<T>::EmptyBallot);
+			ensure!(
+				AccountToCandidateInfo::<T>::contains_key(election_id, &candidate),
+				Error::<T>::NotRegistered
+			);
+
+			let mut ballot =
+				QuadraticBallots::<T>::get(election_id, &voter_account).unwrap_or_default();
+			let held = ballot
+				.iter()
+				.find(|(chosen, _)| *chosen == candidate)
+				.map(|(_, held)| *held)
+				.unwrap_or_default();
+			let total = held.saturating_add(votes);
+			let cost = (total as u64).pow(2) - (held as u64).pow(2);
+
+			let spent = AccountToVoterInfo::<T>::get(election_id, &voter_account)
+				.map(|info| info.credits_spent())
+				.unwrap_or_default();
+			let remaining = election.credits().saturating_sub(spent);
+			ensure!(cost <= remaining as u64, Error::<T>::InsufficientCredits);
+
+			if ballot.is_empty() {
+				VotedCount::<T>::mutate(election_id, |count| *count += 1);
+			}
+			match ballot.iter_mut().find(|(chosen, _)| *chosen == candidate) {
+				Some((_, held)) => *held = total,
+				None => ballot
+					.try_push((candidate.clone(), total))
+					.map_err(|_| Error::<T>::MaxCandidatesExceed)?,
+			}
+			QuadraticBallots::<T>::insert(election_id, &voter_account, ballot);
+			AccountToVoterInfo::<T>::insert(
+				election_id,
+				voter_account,
+				VoterInfo::<T>::quadratic(spent + cost as u32),
+			);
+			Self::count_vote(election_id, &candidate, votes.into());
+
+			Self::deposit_event(Event::QuadraticVoteSuccess { election_id, candidate, votes });
+			Ok(())
+		}
+
+		/// Vote in an election that takes conviction votes, multiplying the ballot's weight by
+		/// `conviction`. The free balance is locked until `conviction.lock_periods()` times
+		/// `ConvictionPeriod` blocks after the election ends, and then released by `unlock`.
+		/// `Conviction::None` keeps it locked only until the election ends.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::give_conviction_vote())]
+		pub fn give_conviction_vote(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			to_vote_for: T::AccountId,
+			conviction: Conviction,
+		) -> DispatchResult {
+			let voter_account = ensure_signed(origin)?;
+
+			ElectionInfo::<T>::ensure_election_progress(election_id)?;
+			let election = ElectionConfig::<T>::get(election_id).unwrap();
+			ensure!(!election.is_commit_reveal(), Error::<T>::SecretBallotRequired);
+			ensure!(election.method() == VotingMethod::Plurality, Error::<T>::WrongBallotType);
+			ensure!(election.takes_conviction(), Error::<T>::ConvictionNotSupported);
+
+			Self::ensure_can_vote(election_id, &voter_account)?;
+
+			let is_candidate =
+				<AccountToCandidateInfo<T>>::contains_key(election_id, to_vote_for.clone());
+			ensure!(is_candidate, Error::<T>::NotRegistered);
+
+			let balance = T::Currency::free_balance(&voter_account);
+			let weight = conviction.votes(balance.unique_saturated_into());
+			ensure!(weight > 0, Error::<T>::NoVotingWeight);
+
+			let lock_time =
+				T::ConvictionPeriod::get().saturating_mul(conviction.lock_periods().into());
+			Self::lock_ballot_balance(election_id, &voter_account, balance, lock_time);
+			let until = election.end_block.unwrap_or_default().saturating_add(lock_time);
+
+			let previous = Self::withdraw_delegation(election_id, &voter_account)?;
+			VotedCount::<T>::mutate(election_id, |count| *count += 1);
+			AccountToVoterInfo::<T>::insert(
+				election_id,
+				voter_account,
+				VoterInfo::<T>::set(true, to_vote_for.clone())
+					.with_weight(weight)
+					.with_delegations_of(&previous),
+			);
+			Self::count_vote(
+				election_id,
+				&to_vote_for,
+				weight.saturating_add(previous.delegated()),
+			);
+
+			Self::deposit_event(Event::ConvictionVoteSuccess { election_id, conviction, until });
+			Ok(())
+		}
+
+		/// Release the balance locked by a token-weighted or conviction vote in `election_id`,
+		/// once its lock period has passed.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::unlock())]
+		pub fn unlock(origin: OriginFor<T>, election_id: ElectionId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let lock = ConvictionLocks::<T>::get(&who, election_id).ok_or(Error::<T>::NoLock)?;
+			let election = ElectionConfig::<T>::get(election_id).unwrap();
+			let until = election.end_block.unwrap_or_default().saturating_add(lock.after_end);
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			let cancelled =
+				ClosedElections::<T>::get(election_id) == Some(ElectionStatus::Cancelled);
+			ensure!(block_number >= until || cancelled, Error::<T>::LockNotExpired);
+
+			ConvictionLocks::<T>::remove(&who, election_id);
+			Self::update_conviction_lock(&who);
+
+			Self::deposit_event(Event::FundsUnlocked { who, election_id });
+			Ok(())
+		}
+
+		/// Hand this voter's vote in `election_id` to another registered voter.
+		/// The weight, plus everything already delegated to this voter, counts towards whatever
+		/// the end of the delegation chain votes for. Voting directly takes the delegation back.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::delegate())]
+		pub fn delegate(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			to: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ElectionInfo::<T>::ensure_election_progress(election_id)?;
+			let election = ElectionConfig::<T>::get(election_id).unwrap();
+			ensure!(
+				election.method() == VotingMethod::Plurality && !election.is_commit_reveal(),
+				Error::<T>::DelegationNotSupported
+			);
+
+			Self::ensure_can_vote(election_id, &who)?;
+			ensure!(Self::is_eligible_voter(election_id, &to), Error::<T>::NotRegistered);
+			ensure!(who != to, Error::<T>::DelegationCycle);
+
+			let mut info =
+				AccountToVoterInfo::<T>::get(election_id, &who).unwrap_or_else(VoterInfo::new);
+			ensure!(info.delegate.is_none(), Error::<T>::AlreadyDelegating);
+
+			let chain = Self::delegation_chain(election_id, &to)?;
+			ensure!(!chain.contains(&who), Error::<T>::DelegationCycle);
+			let depth = info.delegation_depth.saturating_add(chain.len() as u32);
+			ensure!(depth <= T::MaxDelegationDepth::get(), Error::<T>::DelegationTooDeep);
+
+			let weight = Self::ballot_weight(election_id, &election, &who)?;
+			let carried = weight.saturating_add(info.delegated);
+			Self::add_delegation(election_id, &chain, carried, info.delegation_depth + 1);
+
+			info.delegate = Some(to.clone());
+			info.weight = weight;
+			AccountToVoterInfo::<T>::insert(election_id, &who, info);
+
+			Self::deposit_event(Event::Delegated { election_id, who, to });
+			Ok(())
+		}
+
+		/// Take back the vote this voter delegated in `election_id`.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::undelegate())]
+		pub fn undelegate(origin: OriginFor<T>, election_id: ElectionId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ElectionInfo::<T>::ensure_election_progress(election_id)?;
+			let is_delegating = AccountToVoterInfo::<T>::get(election_id, &who)
+				.map_or(false, |info| info.delegate.is_some());
+			ensure!(is_delegating, Error::<T>::NotDelegating);
+
+			Self::withdraw_delegation(election_id, &who)?;
+
+			Self::deposit_event(Event::Undelegated { election_id, who });
+			Ok(())
+		}
+
+		/// Move this voter's plurality vote, along with everything delegated to them, to
+		/// `new_candidate` while the election is open.
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::change_vote())]
+		pub fn change_vote(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			new_candidate: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (mut info, old_candidate) = Self::ensure_open_vote(election_id, &who)?;
+			let is_candidate =
+				AccountToCandidateInfo::<T>::contains_key(election_id, &new_candidate);
+			ensure!(is_candidate, Error::<T>::NotRegistered);
+
+			let carried = info.weight.saturating_add(info.delegated);
+			Self::uncount_vote(election_id, &old_candidate, carried);
+			Self::count_vote(election_id, &new_candidate, carried);
+
+			info.voted_for = Some(new_candidate);
+			AccountToVoterInfo::<T>::insert(election_id, &who, info);
+
+			Self::deposit_event(Event::VoteChanged { election_id, who });
+			Ok(())
+		}
+
+		/// Withdraw this voter's plurality vote while the election is open, so they can vote
+		/// again or delegate. Votes delegated to them stay with them, uncounted until they vote.
+		/// A conviction lock the vote took out is kept.
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::revoke_vote())]
+		pub fn revoke_vote(origin: OriginFor<T>, election_id: ElectionId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (info, candidate) = Self::ensure_open_vote(election_id, &who)?;
+			Self::uncount_vote(election_id, &candidate, info.weight.saturating_add(info.delegated));
+
+			AccountToVoterInfo::<T>::insert(
+				election_id,
+				&who,
+				VoterInfo::<T>::new().with_delegations_of(&info),
+			);
+			VotedCount::<T>::mutate(election_id, |count| *count = count.saturating_sub(1));
+
+			Self::deposit_event(Event::VoteRevoked { election_id, who });
+			Ok(())
+		}
+
+		/// Put `voters` on the voter roll. Accounts already on it are skipped, so a roll can be
+		/// imported in batches that overlap.
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::add_voters(voters.len() as u32))]
+		pub fn add_voters(origin: OriginFor<T>, voters: VoterList<T>) -> DispatchResult {
+			T::ElectionAuthority::ensure_origin(origin)?;
+
+			let mut count = 0;
+			let mut newly_eligible = 0;
+			for voter in voters {
+				if !VoterRoll::<T>::contains_key(&voter) {
+					if !Self::is_registered_voter(&voter) {
+						newly_eligible += 1;
+					}
+					VoterRoll::<T>::insert(voter, ());
+					count += 1;
+				}
+			}
+			VoterRollCount::<T>::mutate(|total| *total = total.saturating_add(count));
+			OpenElectorateCount::<T>::mutate(|total| *total = total.saturating_add(newly_eligible));
+
+			Self::deposit_event(Event::VotersAdded { count });
+			Ok(())
+		}
+
+		/// Take `who` off the voter roll. Votes they have already cast stay counted.
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::remove_voter())]
+		pub fn remove_voter(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::ElectionAuthority::ensure_origin(origin)?;
+
+			ensure!(VoterRoll::<T>::contains_key(&who), Error::<T>::NotOnVoterRoll);
+			VoterRoll::<T>::remove(&who);
+			VoterRollCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+			if !Self::is_registered_voter(&who) {
+				OpenElectorateCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+			}
+
+			Self::deposit_event(Event::VoterRemoved { who });
+			Ok(())
+		}
+
+		/// Enroll as a voter of `election_id` during its registration phase. Elections with a
+		/// registration phase only count the ballots of voters who enrolled.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::enroll())]
+		pub fn enroll(origin: OriginFor<T>, election_id: ElectionId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ElectionInfo::<T>::ensure_registration_progress(election_id)?;
+			let election = ElectionConfig::<T>::get(election_id).unwrap();
+			ensure!(Self::is_in_electorate(&election, &who), Error::<T>::NotRegistered);
+			ensure!(
+				!EnrolledVoters::<T>::contains_key(election_id, &who),
+				Error::<T>::AlreadyRegistered
+			);
+
+			EnrolledVoters::<T>::insert(election_id, &who, ());
+			EnrolledVoterCount::<T>::mutate(election_id, |count| *count = count.saturating_add(1));
+
+			Self::deposit_event(Event::VoterEnrolled { election_id, who });
+			Ok(())
+		}
+
+		/// Call off an election that has not been counted. Candidates get their bonds back, and
+		/// conviction votes can be unlocked straight away.
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::cancel_election())]
+		pub fn cancel_election(origin: OriginFor<T>, election_id: ElectionId) -> DispatchResult {
+			T::ElectionAuthority::ensure_origin(origin)?;
+
+			let election = Self::ensure_open_election(election_id)?;
+			ElectionsToFinalize::<T>::mutate(election.finalize_at(), |due| {
+				due.retain(|id| *id != election_id)
+			});
+			ClosedElections::<T>::insert(election_id, ElectionStatus::Cancelled);
+			PendingPurges::<T>::insert(election_id, PurgeProgress::default());
+			Self::settle_candidacy_bonds(election_id, true);
+
+			Self::deposit_event(Event::ElectionCancelled { election_id });
+			Ok(())
+		}
+
+		/// Move the end of voting later, while voting has not closed. A reveal window keeps
+		/// its length.
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::extend_voting())]
+		pub fn extend_voting(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			new_end: T::BlockNumber,
+		) -> DispatchResult {
+			T::ElectionAuthority::ensure_origin(origin)?;
+
+			let election = Self::ensure_open_election(election_id)?;
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			ensure!(block_number <= election.end_block.unwrap(), Error::<T>::ElectionEnded);
+			ensure!(new_end > election.end_block.unwrap(), Error::<T>::ElectionTimeIllogical);
+
+			let start = election.start_block.unwrap();
+			Self::reschedule(election_id, &election, election.rescheduled(start, new_end))?;
+
+			Self::deposit_event(Event::VotingExtended { election_id, end: new_end });
+			Ok(())
+		}
+
+		/// Move an election that has not opened to vote from `new_start` to `new_end`, no
+		/// earlier than it was due to open. A reveal window keeps its length.
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::postpone())]
+		pub fn postpone(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			new_start: T::BlockNumber,
+			new_end: T::BlockNumber,
+		) -> DispatchResult {
+			T::ElectionAuthority::ensure_origin(origin)?;
+
+			let election = Self::ensure_open_election(election_id)?;
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			ensure!(block_number < election.start_block.unwrap(), Error::<T>::ElectionStarted);
+			ensure!(
+				new_start >= election.start_block.unwrap() && new_start < new_end,
+				Error::<T>::ElectionTimeIllogical
+			);
+
+			Self::reschedule(election_id, &election, election.rescheduled(new_start, new_end))?;
+
+			Self::deposit_event(Event::ElectionPostponed {
+				election_id,
+				start: new_start,
+				end: new_end,
+			});
+			Ok(())
+		}
+
+		/// Remove up to `limit` ballots and candidate records of a closed election, resuming
+		/// where the last batch stopped. Its archived result is kept.
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::purge_election(*limit))]
+		pub fn purge_election(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			limit: u32,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			ensure!(ClosedElections::<T>::contains_key(election_id), Error::<T>::ElectionNotClosed);
+			ensure!(
+				PendingPurges::<T>::contains_key(election_id),
+				Error::<T>::ElectionAlreadyPurged
+			);
+
+			let removed = Self::purge_records(election_id, limit);
+			Ok(Some(T::WeightInfo::purge_election(removed)).into())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		pub fn is_registered_voter(who: &T::AccountId) -> bool {
+			RegisteredVoters::<T>::contains_key(who)
+		}
+
+		/// Where `election_id` stands in its lifecycle, or `None` if there is no such election.
+		pub fn election_status(election_id: ElectionId) -> Option<ElectionStatus> {
+			let election = ElectionConfig::<T>::get(election_id)?;
+			if let Some(status) = ClosedElections::<T>::get(election_id) {
+				return Some(status);
+			}
+
 			let block_number = <frame_system::Pallet<T>>::block_number();
-			ensure!(ElectionConfig::<T>::get().is_some(), Error::<T>::ElectionNotConfigured);
-			let election = ElectionConfig::<T>::get().unwrap();
-			ensure!(block_number >= election.end_block.unwrap(), Error::<T>::ElectionNotEnded);
-
-			// Candidates
-			let mut winner_num = 0;
-			let mut winner_vec: BoundedVec<T::AccountId, ConstU32<100>> = Default::default();
-			for key in AccountToCandidateInfo::<T>::iter_keys() {
-				let candidate_info = AccountToCandidateInfo::<T>::get(key.clone()).unwrap();
-				let votes = candidate_info.clone().vote_count;
-				let max_votes = MaxVote::<T>::get();
-
-				if votes == max_votes {
-					ensure!(winner_num <= 100, Error::<T>::MaxCandidatesExceed);
-					winner_num += 1;
-					winner_vec.try_push(key.clone()).unwrap();
+			let status = if block_number < election.start_block.unwrap() {
+				match election.phases.registration {
+					Some(window) if window.contains(&block_number) => ElectionStatus::Registration,
+					_ => ElectionStatus::Scheduled,
 				}
+			} else if block_number <= election.end_block.unwrap() {
+				ElectionStatus::Voting
+			} else {
+				ElectionStatus::Tallying
+			};
+			Some(status)
+		}
+
+		/// Turnout of a counted election, as archived in `ElectionResults`.
+		pub fn election_turnout(election_id: ElectionId) -> Option<Turnout> {
+			ElectionResults::<T>::get(election_id).map(|result| result.turnout)
+		}
+
+		/// Weight of counting `election` with the candidates and ballots it has. Methods that
+		/// walk every ballot are charged as a ranked count, and the runoff methods, which walk
+		/// them again every round, as an STV count.
+		fn tally_weight(election_id: ElectionId, election: &ElectionInfo<T>) -> Weight {
+			let candidates = CandidateCount::<T>::get(election_id);
+			let ballots = VotedCount::<T>::get(election_id);
+			match election.method() {
+				VotingMethod::Plurality
+				| VotingMethod::Approval
+				| VotingMethod::Score
+				| VotingMethod::Quadratic => T::WeightInfo::winner(candidates),
+				VotingMethod::InstantRunoff | VotingMethod::Stv => {
+					T::WeightInfo::winner_stv(candidates, ballots)
+				},
+				_ => T::WeightInfo::winner_ranked(candidates, ballots),
+			}
+		}
+
+		/// The configuration of `election_id`, provided it has not been finalized, cancelled or
+		/// found invalid.
+		fn ensure_open_election(election_id: ElectionId) -> Result<ElectionInfo<T>, DispatchError> {
+			let election =
+				ElectionConfig::<T>::get(election_id).ok_or(Error::<T>::ElectionNotConfigured)?;
+			ensure!(!ClosedElections::<T>::contains_key(election_id), Error::<T>::ElectionClosed);
+			Ok(election)
+		}
+
+		/// Store `election`'s new schedule and move its finalization to match.
+		fn reschedule(
+			election_id: ElectionId,
+			old: &ElectionInfo<T>,
+			new: ElectionInfo<T>,
+		) -> DispatchResult {
+			if old.finalize_at() != new.finalize_at() {
+				ElectionsToFinalize::<T>::mutate(old.finalize_at(), |due| {
+					due.retain(|id| *id != election_id)
+				});
+				ElectionsToFinalize::<T>::try_append(new.finalize_at(), election_id)
+					.map_err(|_| Error::<T>::TooManyElectionsEnding)?;
+			}
+			ElectionConfig::<T>::insert(election_id, new);
+			Ok(())
+		}
+
+		/// Whether `who` may vote in `election_id`: they belong to its electorate and, if it has
+		/// a registration phase, enrolled in it.
+		pub fn is_eligible_voter(election_id: ElectionId, who: &T::AccountId) -> bool {
+			let Some(election) = ElectionConfig::<T>::get(election_id) else { return false };
+			Self::is_in_electorate(&election, who)
+				&& (election.phases.registration.is_none()
+					|| EnrolledVoters::<T>::contains_key(election_id, who))
+		}
+
+		/// Whether `who` is among the voters the election's `Eligibility` admits.
+		fn is_in_electorate(election: &ElectionInfo<T>, who: &T::AccountId) -> bool {
+			let on_roll = VoterRoll::<T>::contains_key(who);
+			match election.eligibility() {
+				Eligibility::VoterRoll => on_roll,
+				Eligibility::SelfRegistered => on_roll || Self::is_registered_voter(who),
+			}
+		}
+
+		/// The commitment `voter` submits to `commit_vote` in `election_id` for a ballot for
+		/// `candidate`. Binding it to the voter and election keeps it from being copied.
+		pub fn ballot_commitment(
+			election_id: ElectionId,
+			voter: &T::AccountId,
+			candidate: &T::AccountId,
+			salt: &[u8; 32],
+		) -> T::Hash {
+			T::Hashing::hash_of(&(election_id, voter, candidate, salt))
+		}
+
+		fn ensure_can_vote(election_id: ElectionId, voter: &T::AccountId) -> DispatchResult {
+			let is_voter = Self::is_eligible_voter(election_id, voter);
+			ensure!(is_voter, Error::<T>::NotRegistered);
+
+			let voterinfo =
+				<AccountToVoterInfo<T>>::get(election_id, voter).unwrap_or_else(VoterInfo::new);
+			ensure!(!voterinfo.vote_status, Error::<T>::AlreadyVoted);
+
+			Ok(())
+		}
+
+		fn ensure_valid_candidate_list(
+			election_id: ElectionId,
+			candidates: &[T::AccountId],
+		) -> DispatchResult {
+			ensure!(!candidates.is_empty(), Error::<T>::EmptyBallot);
+			for (index, candidate) in candidates.iter().enumerate() {
+				let is_candidate =
+					<AccountToCandidateInfo<T>>::contains_key(election_id, candidate);
+				ensure!(is_candidate, Error::<T>::NotRegistered);
+				ensure!(!candidates[..index].contains(candidate), Error::<T>::DuplicateCandidate);
+			}
+			Ok(())
+		}
+
+		/// What `voter`'s ballot weighs under the election's weighting, as of now. The balance
+		/// a token-weighted ballot counts stays locked until the election ends, so it cannot be
+		/// moved to another voter and counted again.
+		fn ballot_weight(
+			election_id: ElectionId,
+			election: &ElectionInfo<T>,
+			voter: &T::AccountId,
+		) -> Result<VoteWeight, DispatchError> {
+			let balance = match election.weighting() {
+				VoteWeighting::OnePerAccount => return Ok(1),
+				VoteWeighting::FreeBalance => T::Currency::free_balance(voter),
+				VoteWeighting::ReservedBalance => T::Currency::reserved_balance(voter),
+			};
+			let mut weight: VoteWeight = balance.unique_saturated_into();
+			// A ballot cast without a conviction, where one could have been given, would
+			// otherwise outweigh `Conviction::None` with no lock beyond the election's end
+			if election.takes_conviction() {
+				weight = Conviction::None.votes(weight);
+			}
+			ensure!(weight > 0, Error::<T>::NoVotingWeight);
+
+			Self::lock_ballot_balance(election_id, voter, balance, Zero::zero());
+			Ok(weight)
+		}
+
+		/// Lock `amount` of `who`'s balance for `election_id` until `after_end` blocks after the
+		/// election ends. A revoked ballot keeps its lock, so voting again in the same election
+		/// can only extend it.
+		fn lock_ballot_balance(
+			election_id: ElectionId,
+			who: &T::AccountId,
+			amount: BalanceOf<T>,
+			after_end: T::BlockNumber,
+		) {
+			let lock = match ConvictionLocks::<T>::get(who, election_id) {
+				Some(previous) => ConvictionLock::<T> {
+					amount: amount.max(previous.amount),
+					after_end: after_end.max(previous.after_end),
+				},
+				None => ConvictionLock::<T> { amount, after_end },
+			};
+			ConvictionLocks::<T>::insert(who, election_id, lock);
+			Self::update_conviction_lock(who);
+		}
+
+		/// Set `who`'s lock to the largest balance any of their locked votes still holds.
+		fn update_conviction_lock(who: &T::AccountId) {
+			let locked =
+				ConvictionLocks::<T>::iter_prefix_values(who).map(|lock| lock.amount).max();
+			match locked {
+				Some(amount) => {
+					T::Currency::set_lock(CONVICTION_LOCK_ID, who, amount, WithdrawReasons::all())
+				},
+				None => T::Currency::remove_lock(CONVICTION_LOCK_ID, who),
+			}
+		}
+
+		/// Count a closed election: store its winners in `MaxVoteCandidate`, settle its
+		/// candidacy bonds and archive the result. An election short of its quorum is marked
+		/// invalid instead.
+		fn finalize(election_id: ElectionId, election: &ElectionInfo<T>) -> DispatchResult {
+			let turnout = Self::turnout(election_id, election);
+			if let Some(quorum) = election.quorum() {
+				if !turnout.meets(quorum) {
+					Self::settle_candidacy_bonds(election_id, true);
+					Self::archive_result(
+						election_id,
+						election,
+						ElectionStatus::Invalid,
+						turnout,
+						Default::default(),
+					);
+					Self::deposit_event(Event::QuorumNotReached { election_id, turnout });
+					return Ok(());
+				}
+			}
+
+			let mut winner_vec =
+				T::TallyMethods::winners(election.method(), election_id, election)?;
+			// The Schulze count reports the Condorcet winner from its own pairwise matrix
+			if election.method().is_ranked() && election.method() != VotingMethod::Schulze {
+				let (candidates, ballots) = Self::ranked_ballots(election_id);
+				let matrix = tally::pairwise(&candidates, &ballots);
+				Self::report_condorcet_winner(election_id, &candidates, &matrix);
 			}
 			// println!("Winner Vec: {:?}", &winner_vec);
-			MaxVoteCandidate::<T>::put(winner_vec);
-			Self::deposit_event(Event::WinnerVecStored);
+			if let Some(majority) = election.majority() {
+				winner_vec =
+					Self::apply_majority_rule(election_id, election, majority, winner_vec)?;
+			}
+			if winner_vec.len() > 1 && election.method() != VotingMethod::Stv {
+				winner_vec = Self::break_tie(election_id, election, winner_vec)?;
+			}
+			MaxVoteCandidate::<T>::insert(election_id, &winner_vec);
+			Self::settle_candidacy_bonds(election_id, false);
+			Self::archive_result(
+				election_id,
+				election,
+				ElectionStatus::Finalized,
+				turnout,
+				winner_vec,
+			);
+
+			let unrevealed = UnrevealedVotes::<T>::get(election_id);
+			if unrevealed > 0 {
+				Self::deposit_event(Event::UnrevealedVotesReported {
+					election_id,
+					count: unrevealed,
+				});
+			}
+			Self::deposit_event(Event::WinnerVecStored { election_id });
 			Ok(())
 		}
+
+		/// Close a counted election with `status`, and keep its outcome in `ElectionResults`.
+		fn archive_result(
+			election_id: ElectionId,
+			election: &ElectionInfo<T>,
+			status: ElectionStatus,
+			turnout: Turnout,
+			winners: CandidateList<T>,
+		) {
+			let mut candidates: Vec<_> =
+				AccountToCandidateInfo::<T>::iter_prefix(election_id).collect();
+			candidates.sort_by_key(|(_, info)| info.registration_index);
+			let counts = candidates
+				.into_iter()
+				.map(|(candidate, info)| (candidate, info.vote_count))
+				.collect::<Vec<_>>();
+
+			ClosedElections::<T>::insert(election_id, status);
+			PendingPurges::<T>::insert(election_id, PurgeProgress::default());
+			ElectionResults::<T>::insert(
+				election_id,
+				ElectionResult {
+					status,
+					method: election.method(),
+					counts: BoundedVec::truncate_from(counts),
+					winners,
+					turnout,
+					finalized_at: <frame_system::Pallet<T>>::block_number(),
+				},
+			);
+		}
+
+		/// Remove up to `limit` of a closed election's ballots, tally scratch and candidate
+		/// records, starting where the last batch stopped. Returns the number of keys visited. A
+		/// clean election loses its counters and leaves `PendingPurges`.
+		fn purge_records(election_id: ElectionId, limit: u32) -> u32 {
+			const LAST_STAGE: u8 = 10;
+			let Some(mut progress) = PendingPurges::<T>::get(election_id) else { return 0 };
+			let mut visited = 0u32;
+
+			loop {
+				if progress.stage > LAST_STAGE {
+					CandidateCount::<T>::remove(election_id);
+					MaxVote::<T>::remove(election_id);
+					VotedCount::<T>::remove(election_id);
+					EnrolledVoterCount::<T>::remove(election_id);
+					UnrevealedVotes::<T>::remove(election_id);
+					MaxVoteCandidate::<T>::remove(election_id);
+					CondorcetWinner::<T>::remove(election_id);
+					StarFinalists::<T>::remove(election_id);
+					TieBreaks::<T>::remove(election_id);
+					PendingPurges::<T>::remove(election_id);
+					Self::deposit_event(Event::ElectionPurged { election_id });
+					return visited;
+				}
+				let left = limit.saturating_sub(visited);
+				if left == 0 {
+					break;
+				}
+
+				let cursor = progress.cursor.take();
+				let cursor = cursor.as_ref().map(|cursor| cursor.as_slice());
+				let result = match progress.stage {
+					0 => AccountToVoterInfo::<T>::clear_prefix(election_id, left, cursor),
+					1 => EnrolledVoters::<T>::clear_prefix(election_id, left, cursor),
+					2 => RankedBallots::<T>::clear_prefix(election_id, left, cursor),
+					3 => ApprovalBallots::<T>::clear_prefix(election_id, left, cursor),
+					4 => ScoreBallots::<T>::clear_prefix(election_id, left, cursor),
+					5 => QuadraticBallots::<T>::clear_prefix(election_id, left, cursor),
+					6 => BordaScores::<T>::clear_prefix(election_id, left, cursor),
+					7 => PairwisePreferences::<T>::clear_prefix(election_id, left, cursor),
+					8 => IrvRounds::<T>::clear_prefix(election_id, left, cursor),
+					9 => StvRounds::<T>::clear_prefix(election_id, left, cursor),
+					_ => AccountToCandidateInfo::<T>::clear_prefix(election_id, left, cursor),
+				};
+				visited = visited.saturating_add(result.loops);
+				match result.maybe_cursor {
+					// A key too long to keep restarts the map from its prefix, which only
+					// revisits keys removed earlier in the same block
+					Some(cursor) => progress.cursor = PurgeCursor::try_from(cursor).ok(),
+					None => progress.stage += 1,
+				}
+			}
+
+			PendingPurges::<T>::insert(election_id, progress);
+			visited
+		}
+
+		/// Settle a tie between `tied` by the election's `TieBreak`, recording how in
+		/// `TieBreaks`. Returns the winners to store.
+		fn break_tie(
+			election_id: ElectionId,
+			election: &ElectionInfo<T>,
+			tied: CandidateList<T>,
+		) -> Result<CandidateList<T>, DispatchError> {
+			let policy = election.tie_break();
+			if policy == TieBreak::KeepAll {
+				return Ok(tied);
+			}
+
+			let mut by_registration = tied.to_vec();
+			by_registration.sort_by_key(|candidate| {
+				AccountToCandidateInfo::<T>::get(election_id, candidate)
+					.map(|info| info.registration_index)
+			});
+			let (winner, runoff) = match policy {
+				TieBreak::KeepAll => (None, None),
+				TieBreak::Random => {
+					let (seed, _) = T::Randomness::random(&(b"tie-break", election_id).encode());
+					let draw = u32::decode(&mut TrailingZeroInput::new(seed.as_ref()))
+						.unwrap_or_default() as usize;
+					(by_registration.get(draw % by_registration.len()).cloned(), None)
+				},
+				TieBreak::EarliestRegistration => (by_registration.first().cloned(), None),
+				TieBreak::Runoff => {
+					let length =
+						election.end_block.unwrap().saturating_sub(election.start_block.unwrap());
+					(
+						None,
+						Some(Self::schedule_runoff(
+							election_id,
+							election,
+							&by_registration,
+							length,
+						)?),
+					)
+				},
+			};
+
+			let outcome = TieBreakOutcome { policy, tied, winner: winner.clone(), runoff };
+			let winners = outcome.winners();
+			TieBreaks::<T>::insert(election_id, outcome);
+			Self::deposit_event(Event::TieBroken { election_id, policy, winner, runoff });
+			Ok(winners)
+		}
+
+		/// The winners of an election run under `majority`: `winners` if the leader got more
+		/// than the threshold of the ballots, otherwise none while the two leading candidates go
+		/// to a runoff. An election with fewer than two candidates keeps `winners`.
+		fn apply_majority_rule(
+			election_id: ElectionId,
+			election: &ElectionInfo<T>,
+			majority: MajorityRule,
+			winners: CandidateList<T>,
+		) -> Result<CandidateList<T>, DispatchError> {
+			let mut standings: Vec<_> =
+				AccountToCandidateInfo::<T>::iter_prefix(election_id).collect();
+			let total_votes = standings
+				.iter()
+				.fold(0, |total: VoteWeight, (_, info)| total.saturating_add(info.vote_count));
+			let leading = standings.iter().map(|(_, info)| info.vote_count).max().unwrap_or(0);
+			if leading > majority.threshold.mul_floor(total_votes) || standings.len() < 2 {
+				return Ok(winners);
+			}
+
+			standings.sort_by_key(|(_, info)| (Reverse(info.vote_count), info.registration_index));
+			let finalists: Vec<_> =
+				standings.into_iter().take(2).map(|(candidate, _)| candidate).collect();
+			let length = majority.runoff_duration.into();
+			let runoff = Self::schedule_runoff(election_id, election, &finalists, length)?;
+			Self::deposit_event(Event::MajorityNotReached { election_id, runoff });
+			Ok(Default::default())
+		}
+
+		/// Create a plurality election among `candidates`, opening straight away and running
+		/// for `length` blocks, and link it to the election it settles. It settles its own ties
+		/// by earliest registration, in the order `candidates` are given.
+		fn schedule_runoff(
+			election_id: ElectionId,
+			election: &ElectionInfo<T>,
+			candidates: &[T::AccountId],
+			length: T::BlockNumber,
+		) -> Result<ElectionId, DispatchError> {
+			let runoff_id = NextElectionId::<T>::get();
+			let next_id = runoff_id.checked_add(1).ok_or(Error::<T>::ElectionIdOverflow)?;
+
+			// Opening at once also closes the runoff to further candidates
+			let start = <frame_system::Pallet<T>>::block_number();
+			let end = start.saturating_add(length);
+			let settings = ElectionSettings {
+				method: VotingMethod::Plurality,
+				weighting: election.weighting(),
+				conviction: election.conviction(),
+				eligibility: election.eligibility(),
+				tie_break: TieBreak::EarliestRegistration,
+				..Default::default()
+			};
+			let runoff = ElectionInfo::<T>::set(start, end, None, settings);
+			ElectionsToFinalize::<T>::try_append(runoff.finalize_at(), runoff_id)
+				.map_err(|_| Error::<T>::TooManyElectionsEnding)?;
+
+			for (index, candidate) in candidates.iter().enumerate() {
+				if let Some(info) = AccountToCandidateInfo::<T>::get(election_id, candidate) {
+					let entry = CandidateInfo::<T>::new(info.name, info.party, info.manifesto_hash)
+						.with_registration_index(index as u32);
+					AccountToCandidateInfo::<T>::insert(runoff_id, candidate, entry);
+				}
+			}
+			CandidateCount::<T>::insert(runoff_id, candidates.len() as u32);
+			ElectionConfig::<T>::insert(runoff_id, runoff);
+			NextElectionId::<T>::put(next_id);
+			RunoffElection::<T>::insert(election_id, runoff_id);
+			RunoffOf::<T>::insert(runoff_id, election_id);
+
+			Self::deposit_event(Event::ElectionCreated { election_id: runoff_id });
+			Ok(runoff_id)
+		}
+
+		/// How many voters of the election's electorate have a ballot cast.
+		fn turnout(election_id: ElectionId, election: &ElectionInfo<T>) -> Turnout {
+			let voted = VotedCount::<T>::get(election_id);
+			let electorate = if election.phases.registration.is_some() {
+				EnrolledVoterCount::<T>::get(election_id)
+			} else {
+				match election.eligibility() {
+					Eligibility::VoterRoll => VoterRollCount::<T>::get(),
+					Eligibility::SelfRegistered => OpenElectorateCount::<T>::get(),
+				}
+			};
+			Turnout { voted, electorate }
+		}
+
+		/// Return the bond of every candidate with at least `CandidacyBondThreshold` of the
+		/// election's votes, and hand the rest to `ForfeitedBond`. A `void` election returns
+		/// every bond. Settled bonds are zeroed.
+		fn settle_candidacy_bonds(election_id: ElectionId, void: bool) {
+			let total_votes = AccountToCandidateInfo::<T>::iter_prefix_values(election_id)
+				.fold(0, |total: VoteWeight, info| total.saturating_add(info.vote_count));
+			let threshold = if void { Perbill::zero() } else { T::CandidacyBondThreshold::get() };
+			let required = threshold.mul_ceil(total_votes);
+
+			let bonded: Vec<_> = AccountToCandidateInfo::<T>::iter_prefix(election_id)
+				.filter(|(_, info)| !info.bond.is_zero())
+				.collect();
+			for (candidate, mut info) in bonded {
+				let amount = info.bond;
+				info.bond = Zero::zero();
+				AccountToCandidateInfo::<T>::insert(election_id, &candidate, &info);
+
+				if info.vote_count >= required {
+					T::Currency::unreserve(&candidate, amount);
+					Self::deposit_event(Event::BondReturned { election_id, candidate, amount });
+				} else {
+					let (forfeited, _) = T::Currency::slash_reserved(&candidate, amount);
+					T::ForfeitedBond::on_unbalanced(forfeited);
+					Self::deposit_event(Event::BondForfeited { election_id, candidate, amount });
+				}
+			}
+		}
+
+		/// The voter info of `who`'s counted plurality vote in an open election, and the
+		/// candidate it went to.
+		fn ensure_open_vote(
+			election_id: ElectionId,
+			who: &T::AccountId,
+		) -> Result<(VoterInfo<T>, T::AccountId), DispatchError> {
+			ElectionInfo::<T>::ensure_election_progress(election_id)?;
+			let election = ElectionConfig::<T>::get(election_id).unwrap();
+			ensure!(!election.is_commit_reveal(), Error::<T>::SecretBallotRequired);
+			ensure!(election.method() == VotingMethod::Plurality, Error::<T>::WrongBallotType);
+
+			let info =
+				AccountToVoterInfo::<T>::get(election_id, who).ok_or(Error::<T>::NotVoted)?;
+			match info.voted_for.clone() {
+				Some(candidate) if info.vote_status => Ok((info, candidate)),
+				_ => Err(Error::<T>::NotVoted.into()),
+			}
+		}
+
+		/// `from`, followed by every voter its vote is delegated on to.
+		fn delegation_chain(
+			election_id: ElectionId,
+			from: &T::AccountId,
+		) -> Result<Vec<T::AccountId>, DispatchError> {
+			let mut chain = vec![from.clone()];
+			let mut current = from.clone();
+			while let Some(next) =
+				AccountToVoterInfo::<T>::get(election_id, &current).and_then(|info| info.delegate)
+			{
+				ensure!(
+					chain.len() as u32 <= T::MaxDelegationDepth::get(),
+					Error::<T>::DelegationTooDeep
+				);
+				chain.push(next.clone());
+				current = next;
+			}
+			Ok(chain)
+		}
+
+		/// Pass `carried` weight along `chain`. `depth` is the length of the delegation chain
+		/// ending at its first voter.
+		fn add_delegation(
+			election_id: ElectionId,
+			chain: &[T::AccountId],
+			carried: VoteWeight,
+			depth: u32,
+		) {
+			for (hops, voter) in chain.iter().enumerate() {
+				let mut info =
+					AccountToVoterInfo::<T>::get(election_id, voter).unwrap_or_else(VoterInfo::new);
+				info.delegated = info.delegated.saturating_add(carried);
+				info.delegation_depth = info.delegation_depth.max(depth + hops as u32);
+				if let (true, Some(candidate)) = (info.vote_status, info.voted_for.as_ref()) {
+					Self::count_vote(election_id, candidate, carried);
+				}
+				AccountToVoterInfo::<T>::insert(election_id, voter, info);
+			}
+		}
+
+		/// Undo `who`'s delegation, if any, and return their voter info without it.
+		fn withdraw_delegation(
+			election_id: ElectionId,
+			who: &T::AccountId,
+		) -> Result<VoterInfo<T>, DispatchError> {
+			let mut info =
+				AccountToVoterInfo::<T>::get(election_id, who).unwrap_or_else(VoterInfo::new);
+			let Some(to) = info.delegate.take() else { return Ok(info) };
+
+			let carried = info.weight.saturating_add(info.delegated);
+			for voter in Self::delegation_chain(election_id, &to)? {
+				AccountToVoterInfo::<T>::mutate(election_id, &voter, |entry| {
+					let entry = entry.as_mut().unwrap();
+					entry.delegated = entry.delegated.saturating_sub(carried);
+					if let (true, Some(candidate)) = (entry.vote_status, entry.voted_for.as_ref()) {
+						Self::uncount_vote(election_id, candidate, carried);
+					}
+				});
+			}
+
+			AccountToVoterInfo::<T>::insert(election_id, who, &info);
+			Ok(info)
+		}
+
+		/// Candidates of a ranked election, and every ranked ballot cast in it.
+		pub(crate) fn ranked_ballots(
+			election_id: ElectionId,
+		) -> (Vec<T::AccountId>, Vec<Vec<T::AccountId>>) {
+			let candidates = AccountToCandidateInfo::<T>::iter_key_prefix(election_id).collect();
+			let ballots = RankedBallots::<T>::iter_prefix_values(election_id)
+				.map(|ranking| ranking.into_inner())
+				.collect();
+			(candidates, ballots)
+		}
+
+		/// Store and report the Condorcet winner of a ranked election, given its pairwise
+		/// preference `matrix` over `candidates`.
+		pub(crate) fn report_condorcet_winner(
+			election_id: ElectionId,
+			candidates: &[T::AccountId],
+			matrix: &[Vec<u32>],
+		) {
+			if let Some(candidate) = tally::condorcet_winner(candidates, matrix) {
+				CondorcetWinner::<T>::insert(election_id, candidate.clone());
+				Self::deposit_event(Event::CondorcetWinnerFound { election_id, candidate });
+			}
+		}
+
+		/// Take `votes` back from `candidate`, recomputing `MaxVote` over the election's
+		/// candidates.
+		fn uncount_vote(election_id: ElectionId, candidate: &T::AccountId, votes: VoteWeight) {
+			AccountToCandidateInfo::<T>::mutate(election_id, candidate, |val| {
+				let info = val.as_mut().unwrap();
+				info.vote_count = info.vote_count.saturating_sub(votes)
+			});
+
+			let max_votes = AccountToCandidateInfo::<T>::iter_prefix_values(election_id)
+				.map(|info| info.vote_count)
+				.max()
+				.unwrap_or_default();
+			MaxVote::<T>::insert(election_id, max_votes);
+		}
+
+		fn count_vote(election_id: ElectionId, candidate: &T::AccountId, votes: VoteWeight) {
+			AccountToCandidateInfo::<T>::mutate(election_id, candidate, |val| {
+				let info = val.as_mut().unwrap();
+				info.vote_count = info.vote_count.saturating_add(votes)
+			});
+
+			// Max Votes
+			let max_votes = MaxVote::<T>::get(election_id);
+			let candidate_votes =
+				AccountToCandidateInfo::<T>::get(election_id, candidate).unwrap().vote_count;
+			if max_votes < candidate_votes {
+				MaxVote::<T>::insert(election_id, candidate_votes);
+			}
+		}
 	}
 }
@@ -0,0 +1,205 @@
+// Tally methods an election can be counted by, and the set of them a runtime offers.
+
+use crate::{
+	tally, AccountToCandidateInfo, BordaScores, CandidateList, Config, ElectionId, ElectionInfo,
+	Error, IrvRounds, MaxCandidates, MaxVote, PairwisePreferences, Pallet, ScoreBallots,
+	StarFinalists, StvRoundInfo, StvRounds, VoteWeight, VotingMethod,
+};
+use frame_support::{
+	pallet_prelude::*,
+	sp_std::{marker::PhantomData, prelude::*},
+};
+
+/// A counting rule. `winner` calls it once the election is closed, and stores the candidates it
+/// returns in `MaxVoteCandidate`.
+pub trait TallyMethod<T: Config> {
+	/// Winners of `election_id`, in the order the method ranks them. Methods may also write their
+	/// own audit trail to storage.
+	fn winners(
+		election_id: ElectionId,
+		election: &ElectionInfo<T>,
+	) -> Result<CandidateList<T>, DispatchError>;
+}
+
+/// The tally methods a runtime offers, keyed by the `VotingMethod` an election picks.
+///
+/// Runtimes that add their own counting rules implement this for a type that handles their
+/// `VotingMethod::Custom` ids and hands everything else to [`BuiltinTallies`].
+pub trait TallyMethods<T: Config> {
+	/// Whether elections can be created with `method`.
+	fn supports(method: VotingMethod) -> bool;
+
+	/// Count `election_id` with the method the election picked.
+	fn winners(
+		method: VotingMethod,
+		election_id: ElectionId,
+		election: &ElectionInfo<T>,
+	) -> Result<CandidateList<T>, DispatchError>;
+}
+
+/// Every tally method this pallet ships with. `VotingMethod::Custom` is not supported.
+pub struct BuiltinTallies<T>(PhantomData<T>);
+
+impl<T: Config> TallyMethods<T> for BuiltinTallies<T> {
+	fn supports(method: VotingMethod) -> bool {
+		!matches!(method, VotingMethod::Custom(_))
+	}
+
+	fn winners(
+		method: VotingMethod,
+		election_id: ElectionId,
+		election: &ElectionInfo<T>,
+	) -> Result<CandidateList<T>, DispatchError> {
+		match method {
+			VotingMethod::Plurality
+			| VotingMethod::Approval
+			| VotingMethod::Score
+			| VotingMethod::Quadratic => Plurality::winners(election_id, election),
+			VotingMethod::InstantRunoff => InstantRunoff::winners(election_id, election),
+			VotingMethod::Star => Star::winners(election_id, election),
+			VotingMethod::Stv => Stv::winners(election_id, election),
+			VotingMethod::Borda => Borda::winners(election_id, election),
+			VotingMethod::Schulze => Schulze::winners(election_id, election),
+			VotingMethod::Custom(_) => Err(Error::<T>::UnsupportedTallyMethod.into()),
+		}
+	}
+}
+
+/// Candidates whose `vote_count` equals the election's `MaxVote`. Also counts approval, score and
+/// quadratic elections, whose ballots add to `vote_count` as they are cast.
+pub struct Plurality;
+
+impl<T: Config> TallyMethod<T> for Plurality {
+	fn winners(
+		election_id: ElectionId,
+		_election: &ElectionInfo<T>,
+	) -> Result<CandidateList<T>, DispatchError> {
+		let max_votes = MaxVote::<T>::get(election_id);
+		let mut winner_vec: CandidateList<T> = Default::default();
+		for (candidate, info) in AccountToCandidateInfo::<T>::iter_prefix(election_id) {
+			if info.vote_count() == max_votes {
+				winner_vec.try_push(candidate).map_err(|_| Error::<T>::MaxCandidatesExceed)?;
+			}
+		}
+		Ok(winner_vec)
+	}
+}
+
+/// Instant-runoff over the ranked ballots, logging every round in `IrvRounds`.
+pub struct InstantRunoff;
+
+impl<T: Config> TallyMethod<T> for InstantRunoff {
+	fn winners(
+		election_id: ElectionId,
+		_election: &ElectionInfo<T>,
+	) -> Result<CandidateList<T>, DispatchError> {
+		let (candidates, ballots) = Pallet::<T>::ranked_ballots(election_id);
+
+		let outcome = tally::instant_runoff(&candidates, &ballots);
+		for (round, counts) in outcome.rounds.into_iter().enumerate() {
+			let counts: BoundedVec<_, MaxCandidates> =
+				counts.try_into().map_err(|_| Error::<T>::MaxCandidatesExceed)?;
+			IrvRounds::<T>::insert(election_id, round as u32, counts);
+		}
+
+		let winners = outcome.winners.try_into().map_err(|_| Error::<T>::MaxCandidatesExceed)?;
+		Ok(winners)
+	}
+}
+
+/// STAR runoff between the two highest score totals, keeping the finalists in `StarFinalists`.
+pub struct Star;
+
+impl<T: Config> TallyMethod<T> for Star {
+	fn winners(
+		election_id: ElectionId,
+		_election: &ElectionInfo<T>,
+	) -> Result<CandidateList<T>, DispatchError> {
+		let totals: Vec<(T::AccountId, VoteWeight)> =
+			AccountToCandidateInfo::<T>::iter_prefix(election_id)
+				.map(|(candidate, info)| (candidate, info.vote_count()))
+				.collect();
+		let ballots: Vec<Vec<(T::AccountId, u32)>> =
+			ScoreBallots::<T>::iter_prefix_values(election_id)
+				.map(|scores| scores.into_inner())
+				.collect();
+
+		let outcome = tally::star(&totals, &ballots);
+		let finalists: BoundedVec<_, ConstU32<2>> =
+			outcome.finalists.try_into().map_err(|_| Error::<T>::MaxCandidatesExceed)?;
+		StarFinalists::<T>::insert(election_id, finalists);
+
+		let winners = outcome.winners.try_into().map_err(|_| Error::<T>::MaxCandidatesExceed)?;
+		Ok(winners)
+	}
+}
+
+/// Single transferable vote for the election's `seats`, logging every round in `StvRounds`.
+pub struct Stv;
+
+impl<T: Config> TallyMethod<T> for Stv {
+	fn winners(
+		election_id: ElectionId,
+		election: &ElectionInfo<T>,
+	) -> Result<CandidateList<T>, DispatchError> {
+		let (candidates, ballots) = Pallet::<T>::ranked_ballots(election_id);
+
+		let outcome = tally::stv(&candidates, &ballots, election.seats());
+		for (round, info) in outcome.rounds.into_iter().enumerate() {
+			let counts = info.counts.try_into().map_err(|_| Error::<T>::MaxCandidatesExceed)?;
+			let info = StvRoundInfo::<T> { counts, action: info.action };
+			StvRounds::<T>::insert(election_id, round as u32, info);
+		}
+
+		// Ordered by when each candidate was elected
+		let elected = outcome.elected.try_into().map_err(|_| Error::<T>::MaxCandidatesExceed)?;
+		Ok(elected)
+	}
+}
+
+/// Borda count over the ranked ballots, keeping every candidate's points in `BordaScores`.
+pub struct Borda;
+
+impl<T: Config> TallyMethod<T> for Borda {
+	fn winners(
+		election_id: ElectionId,
+		_election: &ElectionInfo<T>,
+	) -> Result<CandidateList<T>, DispatchError> {
+		let (candidates, ballots) = Pallet::<T>::ranked_ballots(election_id);
+
+		let outcome = tally::borda(&candidates, &ballots);
+		for (candidate, points) in outcome.totals {
+			BordaScores::<T>::insert(election_id, candidate, points);
+		}
+
+		let winners = outcome.winners.try_into().map_err(|_| Error::<T>::MaxCandidatesExceed)?;
+		Ok(winners)
+	}
+}
+
+/// Schulze method over the ranked ballots, keeping the pairwise matrix in `PairwisePreferences`.
+/// The same matrix decides the election's Condorcet winner, so `winner` does not build it twice.
+pub struct Schulze;
+
+impl<T: Config> TallyMethod<T> for Schulze {
+	fn winners(
+		election_id: ElectionId,
+		_election: &ElectionInfo<T>,
+	) -> Result<CandidateList<T>, DispatchError> {
+		let (candidates, ballots) = Pallet::<T>::ranked_ballots(election_id);
+
+		let outcome = tally::schulze(&candidates, &ballots);
+		for (i, row) in outcome.pairwise.iter().enumerate() {
+			for (j, preferred) in row.iter().enumerate() {
+				if i != j {
+					let pair = (candidates[i].clone(), candidates[j].clone());
+					PairwisePreferences::<T>::insert(election_id, pair, preferred);
+				}
+			}
+		}
+		Pallet::<T>::report_condorcet_winner(election_id, &candidates, &outcome.pairwise);
+
+		let winners = outcome.winners.try_into().map_err(|_| Error::<T>::MaxCandidatesExceed)?;
+		Ok(winners)
+	}
+}
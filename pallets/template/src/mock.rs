@@ -1,16 +1,25 @@
-use crate::{self as pallet_template, weights::TemplateWeightInfo};
+use crate::{
+	self as pallet_template, weights::TemplateWeightInfo, AccountToCandidateInfo, BuiltinTallies,
+	CandidateList, Conviction, ElectionId, ElectionInfo, ElectionPhases, ElectionSettings,
+	MajorityRule, PhaseWindow, ProfileText, ScoreList, TallyMethod, TallyMethods, TieBreak,
+	VoteWeight, VotingMethod,
+};
 use frame_support::{
-	pallet_prelude::DispatchResult,
-	traits::{ConstU16, ConstU64},
+	pallet_prelude::{DispatchError, DispatchResult},
+	parameter_types,
+	traits::{ConstU16, ConstU32, ConstU64, Currency, OnUnbalanced, Randomness},
+	weights::Weight,
 };
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
+	Perbill,
 };
 
 pub type AccountId = u64;
 pub type BlockNumber = u64;
+pub type Balance = u64;
 type Origin = <Test as frame_system::Config>::RuntimeOrigin;
 
 // type Origin = <Test as frame_system::trait>::Origin;
@@ -20,6 +29,12 @@ pub const BOB: AccountId = 2;
 pub const DAVE: AccountId = 3;
 pub const RON: AccountId = 4;
 pub const JOHN: AccountId = 5;
+// Receives forfeited candidacy bonds
+pub const TREASURY: AccountId = 99;
+
+// JOHN has no balance
+pub const INITIAL_BALANCES: [(AccountId, Balance); 4] =
+	[(ALICE, 100), (BOB, 50), (DAVE, 30), (RON, 20)];
 
 pub fn root_user() -> Origin {
 	RuntimeOrigin::root()
@@ -28,11 +43,21 @@ pub fn who(who: AccountId) -> Origin {
 	RuntimeOrigin::signed(who)
 }
 
+pub const ELECTION_ID: ElectionId = 0;
+pub const OTHER_ELECTION_ID: ElectionId = 1;
+
 pub const ELECTION_START_TIME: u64 = 5;
 pub const ELECTION_END_TIME: u64 = 25;
 pub const TIME_BEFORE_ELECTION: u64 = 2;
 pub const TIME_DURING_ELECTION: u64 = 10;
 pub const TIME_AFTER_ELECTION: u64 = 40;
+pub const REVEAL_END_TIME: u64 = 35;
+pub const TIME_DURING_REVEAL: u64 = 30;
+// Phases of `configure_phased_election`, both before ELECTION_START_TIME
+pub const NOMINATION: PhaseWindow<BlockNumber> = PhaseWindow { start: 1, end: 2 };
+pub const REGISTRATION: PhaseWindow<BlockNumber> = PhaseWindow { start: 3, end: 4 };
+// Voting length of the runoff `setup_majority_election` schedules
+pub const RUNOFF_DURATION: u32 = 15;
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -45,6 +70,7 @@ frame_support::construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system,
+		Balances: pallet_balances,
 		TemplateModule: pallet_template,
 	}
 );
@@ -67,45 +93,165 @@ impl frame_system::Config for Test {
 	type BlockHashCount = ConstU64<250>;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<Balance>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
 	type SS58Prefix = ConstU16<42>;
 	type OnSetCode = ();
-	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	// Set through `ExtBuilder::candidacy_bond`, free otherwise
+	pub static CandidacyBond: Balance = 0;
+	pub const CandidacyBondThreshold: Perbill = Perbill::from_percent(20);
+	// What `TestRandomness` draws
+	pub static RandomDraw: u32 = 0;
+	// Set through `ExtBuilder::max_finalization_weight`, unbounded otherwise
+	pub static MaxFinalizationWeight: Weight = Weight::MAX;
+}
+
+/// Hands out `RandomDraw` as the leading bytes of its seed, so tests choose the draw.
+pub struct TestRandomness;
+
+impl Randomness<H256, BlockNumber> for TestRandomness {
+	fn random(_subject: &[u8]) -> (H256, BlockNumber) {
+		let mut seed = H256::zero();
+		seed[..4].copy_from_slice(&RandomDraw::get().to_le_bytes());
+		(seed, System::block_number())
+	}
+}
+
+/// Pays forfeited candidacy bonds into `TREASURY`.
+pub struct ToTreasury;
+
+impl OnUnbalanced<pallet_balances::NegativeImbalance<Test>> for ToTreasury {
+	fn on_nonzero_unbalanced(amount: pallet_balances::NegativeImbalance<Test>) {
+		Balances::resolve_creating(&TREASURY, amount);
+	}
 }
 
 impl pallet_template::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = TemplateWeightInfo<Test>;
+	type TallyMethods = TestTallies;
+	type Currency = Balances;
+	type ConvictionPeriod = ConstU64<10>;
+	type MaxDelegationDepth = ConstU32<3>;
+	type ElectionAuthority = frame_system::EnsureRoot<AccountId>;
+	type CandidacyBond = CandidacyBond;
+	type CandidacyBondThreshold = CandidacyBondThreshold;
+	type ForfeitedBond = ToTreasury;
+	type MaxElectionsPerBlock = ConstU32<2>;
+	type MaxFinalizationWeight = MaxFinalizationWeight;
+	type Randomness = TestRandomness;
+}
+
+/// `VotingMethod::Custom` id of `FewestFirstPreferences`.
+pub const FEWEST_FIRST_PREFERENCES: u8 = 0;
+
+/// A counting rule the pallet does not ship with: the fewest first preferences wins.
+pub struct FewestFirstPreferences;
+
+impl TallyMethod<Test> for FewestFirstPreferences {
+	fn winners(
+		election_id: ElectionId,
+		_election: &ElectionInfo<Test>,
+	) -> Result<CandidateList<Test>, DispatchError> {
+		let counts: Vec<(AccountId, VoteWeight)> =
+			AccountToCandidateInfo::<Test>::iter_prefix(election_id)
+				.map(|(candidate, info)| (candidate, info.vote_count()))
+				.collect();
+		let fewest = counts.iter().map(|(_, votes)| *votes).min().unwrap_or_default();
+		let winners: Vec<AccountId> = counts
+			.into_iter()
+			.filter(|(_, votes)| *votes == fewest)
+			.map(|(candidate, _)| candidate)
+			.collect();
+		Ok(winners.try_into().unwrap())
+	}
+}
+
+/// The built-in tally methods, plus `FewestFirstPreferences` as a custom one.
+pub struct TestTallies;
+
+impl TallyMethods<Test> for TestTallies {
+	fn supports(method: VotingMethod) -> bool {
+		method == VotingMethod::Custom(FEWEST_FIRST_PREFERENCES)
+			|| BuiltinTallies::<Test>::supports(method)
+	}
+
+	fn winners(
+		method: VotingMethod,
+		election_id: ElectionId,
+		election: &ElectionInfo<Test>,
+	) -> Result<CandidateList<Test>, DispatchError> {
+		match method {
+			VotingMethod::Custom(FEWEST_FIRST_PREFERENCES) => {
+				FewestFirstPreferences::winners(election_id, election)
+			},
+			_ => BuiltinTallies::<Test>::winners(method, election_id, election),
+		}
+	}
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	frame_system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+	ExtBuilder::default().build()
 }
 
-pub struct ExtBuilder;
+pub struct ExtBuilder {
+	candidacy_bond: Balance,
+	max_finalization_weight: Weight,
+}
 
 impl Default for ExtBuilder {
 	fn default() -> Self {
-		ExtBuilder
+		ExtBuilder { candidacy_bond: 0, max_finalization_weight: Weight::MAX }
 	}
 }
 
 impl ExtBuilder {
+	pub fn candidacy_bond(mut self, bond: Balance) -> Self {
+		self.candidacy_bond = bond;
+		self
+	}
+
+	pub fn max_finalization_weight(mut self, weight: Weight) -> Self {
+		self.max_finalization_weight = weight;
+		self
+	}
+
 	pub fn build(self) -> sp_io::TestExternalities {
-		let t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+		CandidacyBond::set(&self.candidacy_bond);
+		MaxFinalizationWeight::set(&self.max_finalization_weight);
+
+		let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+		pallet_balances::GenesisConfig::<Test> { balances: INITIAL_BALANCES.to_vec() }
+			.assimilate_storage(&mut t)
+			.unwrap();
 
 		t.into()
 	}
 }
 
 pub fn setup_for_one_voter_one_candidate_and_election_time() {
+	configure_election_start_and_end_time();
 	register_voter(who(ALICE));
 	register_candidate(who(BOB));
-	configure_election_start_and_end_time();
 }
 
 pub fn set_current_time(time: u64) {
@@ -122,8 +268,16 @@ pub fn register_voters(arr: &[AccountId]) {
 	}
 }
 
+pub fn candidate_name() -> ProfileText {
+	b"Candidate".to_vec().try_into().unwrap()
+}
+
 pub fn register_candidate(who: Origin) -> DispatchResult {
-	TemplateModule::register_candidate(who)
+	register_candidate_in(who, ELECTION_ID)
+}
+
+pub fn register_candidate_in(who: Origin, election_id: ElectionId) -> DispatchResult {
+	TemplateModule::register_candidate(who, election_id, candidate_name(), None, H256::zero())
 }
 
 pub fn register_candidates(arr: &[AccountId]) {
@@ -133,15 +287,144 @@ pub fn register_candidates(arr: &[AccountId]) {
 }
 
 pub fn give_vote(from: Origin, to: AccountId) -> DispatchResult {
-	TemplateModule::give_vote(from, to)
+	TemplateModule::give_vote(from, ELECTION_ID, to)
 }
 
 pub fn configure_election_start_and_end_time() -> DispatchResult {
-	TemplateModule::config_election(root_user(), ELECTION_START_TIME, ELECTION_END_TIME)
+	configure_election_with(VotingMethod::Plurality)
+}
+
+pub fn configure_election_with(method: VotingMethod) -> DispatchResult {
+	configure_election_with_settings(ElectionSettings { method, ..Default::default() })
+}
+
+pub fn configure_election_with_settings(settings: ElectionSettings) -> DispatchResult {
+	TemplateModule::create_election(
+		root_user(),
+		ELECTION_START_TIME,
+		ELECTION_END_TIME,
+		None,
+		settings,
+		Default::default(),
+	)
+}
+
+pub fn configure_commit_reveal_election() -> DispatchResult {
+	TemplateModule::create_election(
+		root_user(),
+		ELECTION_START_TIME,
+		ELECTION_END_TIME,
+		Some(REVEAL_END_TIME),
+		Default::default(),
+		Default::default(),
+	)
+}
+
+pub fn configure_phased_election() -> DispatchResult {
+	TemplateModule::create_election(
+		root_user(),
+		ELECTION_START_TIME,
+		ELECTION_END_TIME,
+		None,
+		Default::default(),
+		ElectionPhases { nomination: Some(NOMINATION), registration: Some(REGISTRATION) },
+	)
+}
+
+pub fn enroll(who: Origin) -> DispatchResult {
+	TemplateModule::enroll(who, ELECTION_ID)
+}
+
+pub fn give_ranked_vote(from: Origin, ranking: &[AccountId]) -> DispatchResult {
+	let ranking: CandidateList<Test> = ranking.to_vec().try_into().unwrap();
+	TemplateModule::give_ranked_vote(from, ELECTION_ID, ranking)
+}
+
+pub fn give_approval_vote(from: Origin, approved: &[AccountId]) -> DispatchResult {
+	let approved: CandidateList<Test> = approved.to_vec().try_into().unwrap();
+	TemplateModule::give_approval_vote(from, ELECTION_ID, approved)
+}
+
+/// An election that ends with RON and DAVE tied for the win. RON registered before DAVE.
+pub fn setup_tied_election(tie_break: TieBreak) {
+	set_current_time(1);
+	configure_election_with_settings(ElectionSettings { tie_break, ..Default::default() });
+	register_candidates(&[BOB, RON, DAVE]);
+	register_voters(&[ALICE, JOHN]);
+
+	set_current_time(TIME_DURING_ELECTION);
+	give_vote(who(ALICE), DAVE);
+	give_vote(who(JOHN), RON);
+	set_current_time(TIME_AFTER_ELECTION);
+}
+
+/// A plurality election needing more than half the ballots, closed after `ballots` were cast.
+/// BOB, RON and DAVE stand, registering in that order.
+pub fn setup_majority_election(ballots: &[(AccountId, AccountId)]) {
+	set_current_time(1);
+	let majority =
+		MajorityRule { threshold: Perbill::from_percent(50), runoff_duration: RUNOFF_DURATION };
+	configure_election_with_settings(ElectionSettings {
+		majority: Some(majority),
+		..Default::default()
+	});
+	register_candidates(&[BOB, RON, DAVE]);
+
+	set_current_time(TIME_DURING_ELECTION);
+	for (voter, candidate) in ballots {
+		register_voter(who(*voter));
+		give_vote(who(*voter), *candidate);
+	}
+	set_current_time(TIME_AFTER_ELECTION);
+}
+
+pub fn add_voters(voters: &[AccountId]) -> DispatchResult {
+	TemplateModule::add_voters(root_user(), voters.to_vec().try_into().unwrap())
+}
+
+pub fn give_conviction_vote(from: Origin, to: AccountId, conviction: Conviction) -> DispatchResult {
+	TemplateModule::give_conviction_vote(from, ELECTION_ID, to, conviction)
+}
+
+pub fn delegate(from: Origin, to: AccountId) -> DispatchResult {
+	TemplateModule::delegate(from, ELECTION_ID, to)
+}
+
+pub fn undelegate(from: Origin) -> DispatchResult {
+	TemplateModule::undelegate(from, ELECTION_ID)
+}
+
+pub fn change_vote(from: Origin, to: AccountId) -> DispatchResult {
+	TemplateModule::change_vote(from, ELECTION_ID, to)
+}
+
+pub fn revoke_vote(from: Origin) -> DispatchResult {
+	TemplateModule::revoke_vote(from, ELECTION_ID)
+}
+
+pub fn give_votes(from: Origin, to: AccountId, votes: u32) -> DispatchResult {
+	TemplateModule::give_votes(from, ELECTION_ID, to, votes)
+}
+
+pub fn give_score_vote(from: Origin, scores: &[(AccountId, u32)]) -> DispatchResult {
+	let scores: ScoreList<Test> = scores.to_vec().try_into().unwrap();
+	TemplateModule::give_score_vote(from, ELECTION_ID, scores)
+}
+
+pub fn commit_vote(from: Origin, to: AccountId, salt: [u8; 32]) -> DispatchResult {
+	let voter = frame_system::ensure_signed(from.clone())?;
+	let commitment = TemplateModule::ballot_commitment(ELECTION_ID, &voter, &to, &salt);
+	TemplateModule::commit_vote(from, ELECTION_ID, commitment)
+}
+
+pub fn reveal_vote(from: Origin, to: AccountId, salt: [u8; 32]) -> DispatchResult {
+	TemplateModule::reveal_vote(from, ELECTION_ID, to, salt)
 }
 
 pub fn who_won_elections() -> DispatchResult {
-	TemplateModule::winner(who(ALICE))
+	TemplateModule::winner(who(ALICE), ELECTION_ID, TemplateModule::voted_count(ELECTION_ID))
+		.map(|_| ())
+		.map_err(|error| error.error)
 }
 
 // pub fn account_info_of_voter(whose: AccountId)-> Option<>{
@@ -0,0 +1,359 @@
+// Counting rules that work on plain candidate and ballot lists, independent of storage.
+
+use frame_support::{
+	pallet_prelude::{Decode, Encode, MaxEncodedLen, RuntimeDebug, TypeInfo},
+	sp_std::{collections::btree_map::BTreeMap, prelude::*},
+};
+
+/// Result of an instant-runoff count.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct IrvOutcome<AccountId> {
+	// Every round's count over the candidates still in the race, first round first
+	pub rounds: Vec<Vec<(AccountId, u32)>>,
+	// Single winner, or every remaining candidate when they are all tied
+	pub winners: Vec<AccountId>,
+}
+
+/// Instant-runoff voting.
+///
+/// Each ballot counts for its highest ranked candidate still in the race. A candidate with more
+/// than half of the counted ballots wins, otherwise the lowest candidates are eliminated and the
+/// next round is counted. Candidates tied for lowest are eliminated together, unless that would
+/// eliminate everyone left, in which case they all win.
+pub fn instant_runoff<AccountId: Ord + Clone>(
+	candidates: &[AccountId],
+	ballots: &[Vec<AccountId>],
+) -> IrvOutcome<AccountId> {
+	let mut remaining = candidates.to_vec();
+	let mut rounds = Vec::new();
+
+	while !remaining.is_empty() {
+		let mut counts: BTreeMap<AccountId, u32> =
+			remaining.iter().map(|candidate| (candidate.clone(), 0)).collect();
+		let mut counted = 0u32;
+		for ballot in ballots {
+			if let Some(choice) = ballot.iter().find(|candidate| counts.contains_key(*candidate)) {
+				*counts.get_mut(choice).unwrap() += 1;
+				counted += 1;
+			}
+		}
+
+		let round: Vec<(AccountId, u32)> = remaining
+			.iter()
+			.map(|candidate| (candidate.clone(), counts[candidate]))
+			.collect();
+		rounds.push(round.clone());
+
+		if let Some((leader, _)) = round.iter().find(|(_, votes)| votes.saturating_mul(2) > counted)
+		{
+			return IrvOutcome { rounds, winners: vec![leader.clone()] };
+		}
+
+		let lowest = round.iter().map(|(_, votes)| *votes).min().unwrap_or_default();
+		let survivors: Vec<AccountId> = round
+			.into_iter()
+			.filter(|(_, votes)| *votes > lowest)
+			.map(|(candidate, _)| candidate)
+			.collect();
+
+		if survivors.is_empty() {
+			return IrvOutcome { rounds, winners: remaining };
+		}
+		remaining = survivors;
+	}
+
+	IrvOutcome { rounds, winners: Vec::new() }
+}
+
+/// Result of a STAR (score then automatic runoff) count.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct StarOutcome<AccountId> {
+	// The two highest scoring candidates, each with the number of ballots preferring them
+	pub finalists: Vec<(AccountId, u32)>,
+	// Runoff winner, or both finalists when they are tied on preferences and on score
+	pub winners: Vec<AccountId>,
+}
+
+/// STAR voting: the two candidates with the highest total score go to an automatic runoff, won by
+/// the finalist that more ballots scored higher. A runoff tie goes to the higher total score.
+/// Candidates tied on total score are ordered by account so the finalists are deterministic.
+pub fn star<AccountId: Ord + Clone, Total: Ord + Copy>(
+	totals: &[(AccountId, Total)],
+	ballots: &[Vec<(AccountId, u32)>],
+) -> StarOutcome<AccountId> {
+	let mut ranked = totals.to_vec();
+	ranked.sort_by(|(a, a_score), (b, b_score)| b_score.cmp(a_score).then_with(|| a.cmp(b)));
+
+	let (first, first_score, second, second_score) = match ranked.as_slice() {
+		[] => return StarOutcome { finalists: Vec::new(), winners: Vec::new() },
+		[(only, _)] => {
+			return StarOutcome { finalists: vec![(only.clone(), 0)], winners: vec![only.clone()] }
+		},
+		[(first, first_score), (second, second_score), ..] => {
+			(first.clone(), *first_score, second.clone(), *second_score)
+		},
+	};
+
+	let score_of = |ballot: &Vec<(AccountId, u32)>, candidate: &AccountId| {
+		ballot.iter().find(|(scored, _)| scored == candidate).map(|(_, score)| *score)
+	};
+	let (mut prefer_first, mut prefer_second) = (0u32, 0u32);
+	for ballot in ballots {
+		let first_given = score_of(ballot, &first).unwrap_or_default();
+		let second_given = score_of(ballot, &second).unwrap_or_default();
+		if first_given > second_given {
+			prefer_first += 1;
+		} else if second_given > first_given {
+			prefer_second += 1;
+		}
+	}
+
+	let winners = if prefer_first != prefer_second {
+		if prefer_first > prefer_second {
+			vec![first.clone()]
+		} else {
+			vec![second.clone()]
+		}
+	} else if first_score != second_score {
+		vec![first.clone()]
+	} else {
+		vec![first.clone(), second.clone()]
+	};
+
+	StarOutcome { finalists: vec![(first, prefer_first), (second, prefer_second)], winners }
+}
+
+/// One vote in an STV count. Transferred surpluses carry a fraction of a vote, so STV counts are
+/// kept in millionths of a vote.
+pub const STV_VOTE: u64 = 1_000_000;
+
+/// What an STV round did after counting.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub enum StvAction<AccountId> {
+	// Reached the quota. The surplus above the quota moves on to the next preferences.
+	Elected { candidate: AccountId, surplus: u64 },
+	// Lowest count without any candidate reaching the quota. Their ballots move on in full.
+	Eliminated { candidate: AccountId },
+	// Took one of the last seats because no more candidates were left than seats
+	ElectedWithoutQuota { candidate: AccountId },
+}
+
+/// Counts of the candidates still in the race, in `STV_VOTE` units, and what was done with them.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct StvRound<AccountId> {
+	pub counts: Vec<(AccountId, u64)>,
+	pub action: StvAction<AccountId>,
+}
+
+/// Result of a single transferable vote count.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct StvOutcome<AccountId> {
+	// Elected candidates in the order they were elected
+	pub elected: Vec<AccountId>,
+	pub rounds: Vec<StvRound<AccountId>>,
+}
+
+/// Single transferable vote with a Droop quota of `ballots / (seats + 1) + 1`.
+///
+/// Each round either elects the highest candidate at or above the quota, passing every ballot
+/// that elected them on at the fraction `surplus / count`, or eliminates the lowest candidate.
+/// Once there are no more candidates left than open seats they are elected in order of their
+/// count. Ties are broken by account order.
+pub fn stv<AccountId: Ord + Clone>(
+	candidates: &[AccountId],
+	ballots: &[Vec<AccountId>],
+	seats: u32,
+) -> StvOutcome<AccountId> {
+	let mut hopeful = candidates.to_vec();
+	hopeful.sort();
+	let mut weighted: Vec<(&Vec<AccountId>, u64)> = ballots
+		.iter()
+		.filter(|ballot| ballot.iter().any(|choice| hopeful.contains(choice)))
+		.map(|ballot| (ballot, STV_VOTE))
+		.collect();
+	let quota = (weighted.len() as u64 / (seats as u64 + 1) + 1) * STV_VOTE;
+
+	let mut elected = Vec::new();
+	let mut rounds = Vec::new();
+	while (elected.len() as u32) < seats && !hopeful.is_empty() {
+		let top_choice = |ballot: &Vec<AccountId>, hopeful: &Vec<AccountId>| {
+			ballot.iter().find(|choice| hopeful.contains(*choice)).cloned()
+		};
+		let mut counts: BTreeMap<AccountId, u64> =
+			hopeful.iter().map(|candidate| (candidate.clone(), 0)).collect();
+		for (ballot, weight) in weighted.iter() {
+			if let Some(choice) = top_choice(ballot, &hopeful) {
+				*counts.get_mut(&choice).unwrap() += *weight;
+			}
+		}
+		let counts: Vec<(AccountId, u64)> = counts.into_iter().collect();
+
+		// Highest count first, then account order
+		let leader = counts
+			.iter()
+			.fold(None, |best: Option<&(AccountId, u64)>, entry| match best {
+				Some(best) if best.1 >= entry.1 => Some(best),
+				_ => Some(entry),
+			})
+			.cloned()
+			.unwrap();
+		let open_seats = seats as usize - elected.len();
+
+		let action = if leader.1 >= quota {
+			let (candidate, count) = leader;
+			let surplus = count - quota;
+			for (ballot, weight) in weighted.iter_mut() {
+				if top_choice(ballot, &hopeful).as_ref() == Some(&candidate) {
+					*weight = (*weight as u128 * surplus as u128 / count as u128) as u64;
+				}
+			}
+			StvAction::Elected { candidate, surplus }
+		} else if hopeful.len() <= open_seats {
+			StvAction::ElectedWithoutQuota { candidate: leader.0 }
+		} else {
+			// Lowest count first, then account order
+			let (candidate, _) = counts
+				.iter()
+				.fold(None, |worst: Option<&(AccountId, u64)>, entry| match worst {
+					Some(worst) if worst.1 <= entry.1 => Some(worst),
+					_ => Some(entry),
+				})
+				.cloned()
+				.unwrap();
+			StvAction::Eliminated { candidate }
+		};
+
+		match &action {
+			StvAction::Elected { candidate, .. } | StvAction::ElectedWithoutQuota { candidate } => {
+				hopeful.retain(|hopeful| hopeful != candidate);
+				elected.push(candidate.clone());
+			},
+			StvAction::Eliminated { candidate } => hopeful.retain(|hopeful| hopeful != candidate),
+		}
+		rounds.push(StvRound { counts, action });
+	}
+
+	StvOutcome { elected, rounds }
+}
+
+/// Result of a Borda count.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct BordaOutcome<AccountId> {
+	// Every candidate's points, in the order the candidates were given
+	pub totals: Vec<(AccountId, u32)>,
+	// Candidates with the most points
+	pub winners: Vec<AccountId>,
+}
+
+/// Borda count: with `n` candidates, a ballot gives `n - 1` points to its first choice, `n - 2` to
+/// its second and so on. Candidates left off a ballot get no points from it.
+pub fn borda<AccountId: Ord + Clone>(
+	candidates: &[AccountId],
+	ballots: &[Vec<AccountId>],
+) -> BordaOutcome<AccountId> {
+	let top_points = candidates.len().saturating_sub(1) as u32;
+	let mut points: BTreeMap<AccountId, u32> =
+		candidates.iter().map(|candidate| (candidate.clone(), 0)).collect();
+	for ballot in ballots {
+		for (position, choice) in ballot.iter().enumerate() {
+			if let Some(total) = points.get_mut(choice) {
+				*total = total.saturating_add(top_points.saturating_sub(position as u32));
+			}
+		}
+	}
+
+	let totals: Vec<(AccountId, u32)> = candidates
+		.iter()
+		.map(|candidate| (candidate.clone(), points[candidate]))
+		.collect();
+	let most = totals.iter().map(|(_, total)| *total).max().unwrap_or_default();
+	let winners = totals
+		.iter()
+		.filter(|(_, total)| *total == most)
+		.map(|(candidate, _)| candidate.clone())
+		.collect();
+	BordaOutcome { totals, winners }
+}
+
+/// Pairwise preference matrix over `candidates`: entry `[i][j]` is the number of ballots ranking
+/// candidate `i` above candidate `j`. A ranked candidate is preferred to every unranked one.
+pub fn pairwise<AccountId: Ord + Clone>(
+	candidates: &[AccountId],
+	ballots: &[Vec<AccountId>],
+) -> Vec<Vec<u32>> {
+	let n = candidates.len();
+	let mut matrix = vec![vec![0u32; n]; n];
+	for ballot in ballots {
+		let position = |candidate: &AccountId| {
+			ballot.iter().position(|choice| choice == candidate).unwrap_or(usize::MAX)
+		};
+		let positions: Vec<usize> = candidates.iter().map(position).collect();
+		for (i, row) in matrix.iter_mut().enumerate() {
+			for (j, preferred) in row.iter_mut().enumerate() {
+				if positions[i] < positions[j] {
+					*preferred += 1;
+				}
+			}
+		}
+	}
+	matrix
+}
+
+/// The candidate preferred to every other candidate by more ballots than the other way round, if
+/// there is one.
+pub fn condorcet_winner<AccountId: Clone>(
+	candidates: &[AccountId],
+	matrix: &[Vec<u32>],
+) -> Option<AccountId> {
+	(0..candidates.len())
+		.find(|&i| (0..candidates.len()).all(|j| i == j || matrix[i][j] > matrix[j][i]))
+		.map(|i| candidates[i].clone())
+}
+
+/// Result of a Schulze count.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct SchulzeOutcome<AccountId> {
+	// Pairwise preference matrix, indexed in the order the candidates were given
+	pub pairwise: Vec<Vec<u32>>,
+	// Candidates no other candidate beats through a stronger path
+	pub winners: Vec<AccountId>,
+}
+
+/// Schulze method. The strength of a path between two candidates is its weakest pairwise win,
+/// measured in winning votes. A candidate wins when their strongest path to every other candidate
+/// is at least as strong as the strongest path back. The Condorcet winner, when there is one, is
+/// always the only winner.
+pub fn schulze<AccountId: Ord + Clone>(
+	candidates: &[AccountId],
+	ballots: &[Vec<AccountId>],
+) -> SchulzeOutcome<AccountId> {
+	let n = candidates.len();
+	let pairwise = pairwise(candidates, ballots);
+
+	let mut strength = vec![vec![0u32; n]; n];
+	for (i, row) in strength.iter_mut().enumerate() {
+		for (j, path) in row.iter_mut().enumerate() {
+			if pairwise[i][j] > pairwise[j][i] {
+				*path = pairwise[i][j];
+			}
+		}
+	}
+	// Widest paths, one intermediate candidate at a time
+	for k in 0..n {
+		let from_k = strength[k].clone();
+		for (i, row) in strength.iter_mut().enumerate() {
+			let to_k = row[k];
+			for (j, path) in row.iter_mut().enumerate() {
+				if i != j && i != k && j != k {
+					*path = (*path).max(to_k.min(from_k[j]));
+				}
+			}
+		}
+	}
+
+	let winners = (0..n)
+		.filter(|&i| (0..n).all(|j| i == j || strength[i][j] >= strength[j][i]))
+		.map(|i| candidates[i].clone())
+		.collect();
+	SchulzeOutcome { pairwise, winners }
+}
@@ -1,19 +1,31 @@
-use crate::{mock::*, CandidateInfo, ElectionInfo, Error, Event, VoterInfo};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+	mock::*, tally, CandidateInfo, Config, Conviction, ElectionInfo, ElectionPhases,
+	ElectionSettings, ElectionStatus, Eligibility, Error, Event, MajorityRule, PhaseWindow,
+	ProfileText, TieBreak, Turnout, VoteWeighting, VoterInfo, VotingMethod, WeightInfo,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	pallet_prelude::DispatchError,
+	traits::{Hooks, ReservableCurrency},
+	weights::Weight,
+};
+use sp_core::H256;
+use sp_runtime::Perbill;
 // use crate as pallet_template;
 
 // ! Funda: give when then
 
 // ! Split code into meaningful files
 
-
 #[test]
 fn test_register_voter() {
 	ExtBuilder::default().build().execute_with(|| {
 		set_current_time(TIME_DURING_ELECTION);
 
 		assert_ok!(register_voter(who(ALICE)));
-		assert_eq!(TemplateModule::voter_account(ALICE).unwrap(), VoterInfo::new());
+		assert!(TemplateModule::is_registered_voter(&ALICE));
+		// Participation is only recorded once the voter takes part in an election
+		assert_eq!(TemplateModule::voter_account(ELECTION_ID, ALICE), None);
 
 		System::assert_last_event(Event::RegisterVoter.into());
 	})
@@ -22,12 +34,16 @@ fn test_register_voter() {
 #[test]
 fn test_register_candidate() {
 	ExtBuilder::default().build().execute_with(|| {
-		set_current_time(TIME_DURING_ELECTION);
+		configure_election_start_and_end_time();
+		set_current_time(TIME_BEFORE_ELECTION);
 
 		assert_ok!(register_candidate(who(BOB)));
-		assert_eq!(TemplateModule::candidate_account(BOB).unwrap(), CandidateInfo::new());
+		assert_eq!(
+			TemplateModule::candidate_account(ELECTION_ID, BOB).unwrap(),
+			CandidateInfo::new(candidate_name(), None, H256::zero())
+		);
 
-		System::assert_last_event(Event::RegisterCandidate.into());
+		System::assert_last_event(Event::RegisterCandidate { election_id: ELECTION_ID }.into());
 	})
 }
 
@@ -37,9 +53,13 @@ fn test_configuring_election() {
 		set_current_time(TIME_DURING_ELECTION);
 
 		assert_ok!(configure_election_start_and_end_time());
-		assert_eq!(TemplateModule::get_election().unwrap(), ElectionInfo::<Test>::voted());
+		assert_eq!(
+			TemplateModule::get_election(ELECTION_ID).unwrap(),
+			ElectionInfo::<Test>::voted()
+		);
+		assert_eq!(TemplateModule::next_election_id(), ELECTION_ID + 1);
 
-		System::assert_last_event(Event::ElectionConfigured.into());
+		System::assert_last_event(Event::ElectionCreated { election_id: ELECTION_ID }.into());
 	})
 }
 
@@ -51,10 +71,13 @@ fn test_voter_giving_vote_to_candidate_during_election() {
 
 		give_vote(who(ALICE), BOB);
 
-		assert_eq!(TemplateModule::candidate_account(BOB).unwrap(), CandidateInfo::voted());
-		assert_eq!(TemplateModule::voter_account(ALICE).unwrap(), VoterInfo::voted());
+		assert_eq!(
+			TemplateModule::candidate_account(ELECTION_ID, BOB).unwrap(),
+			CandidateInfo::voted()
+		);
+		assert_eq!(TemplateModule::voter_account(ELECTION_ID, ALICE).unwrap(), VoterInfo::voted());
 
-		System::assert_last_event(Event::VoteSuccess.into());
+		System::assert_last_event(Event::VoteSuccess { election_id: ELECTION_ID }.into());
 	})
 }
 
@@ -81,9 +104,9 @@ fn test_voter_giving_vote_to_candidate_after_election() {
 #[test]
 fn test_decided_winner_after_election_ended() {
 	ExtBuilder::default().build().execute_with(|| {
+		configure_election_start_and_end_time();
 		register_voters(&[ALICE, BOB, DAVE, JOHN, RON]);
 		register_candidates(&[DAVE, JOHN, RON]);
-		configure_election_start_and_end_time();
 
 		// -------------------------------- Voting -------------------------------
 		set_current_time(TIME_DURING_ELECTION);
@@ -108,9 +131,9 @@ fn test_decided_winner_after_election_ended() {
 			vec![DAVE, RON].try_into().unwrap();
 
 		assert_ok!(who_won_elections());
-		assert_eq!(TemplateModule::max_votes_candidate().unwrap(), win);
+		assert_eq!(TemplateModule::max_votes_candidate(ELECTION_ID).unwrap(), win);
 
-		System::assert_last_event(Event::WinnerVecStored.into());
+		System::assert_last_event(Event::WinnerVecStored { election_id: ELECTION_ID }.into());
 	})
 }
 
@@ -127,7 +150,8 @@ fn test_raise_error_when_voter_registers_twice() {
 #[test]
 fn test_raise_error_when_candidate_registers_twice() {
 	ExtBuilder::default().build().execute_with(|| {
-		set_current_time(TIME_DURING_ELECTION);
+		configure_election_start_and_end_time();
+		set_current_time(TIME_BEFORE_ELECTION);
 
 		register_candidate(who(BOB));
 		assert_noop!(register_candidate(who(BOB)), Error::<Test>::AlreadyRegistered);
@@ -137,10 +161,10 @@ fn test_raise_error_when_candidate_registers_twice() {
 #[test]
 fn test_raise_error_when_voter_already_voted() {
 	ExtBuilder::default().build().execute_with(|| {
+		configure_election_start_and_end_time();
 		register_voter(who(ALICE));
 		register_candidate(who(BOB));
 
-		configure_election_start_and_end_time();
 		set_current_time(TIME_DURING_ELECTION);
 
 		give_vote(who(ALICE), BOB);
@@ -152,9 +176,9 @@ fn test_raise_error_when_voter_already_voted() {
 fn test_raise_error_for_voting_when_election_not_configured() {
 	ExtBuilder::default().build().execute_with(|| {
 		set_current_time(TIME_DURING_ELECTION);
-		register_voter(who(ALICE));
-		register_candidate(who(BOB));
 
+		assert_ok!(register_voter(who(ALICE)));
+		assert_noop!(register_candidate(who(BOB)), Error::<Test>::ElectionNotConfigured);
 		assert_noop!(give_vote(who(ALICE), BOB), Error::<Test>::ElectionNotConfigured);
 	})
 }
@@ -162,9 +186,9 @@ fn test_raise_error_for_voting_when_election_not_configured() {
 #[test]
 fn test_raise_error_when_voting_by_not_registered_voter() {
 	ExtBuilder::default().build().execute_with(|| {
+		configure_election_start_and_end_time();
 		register_candidate(who(BOB));
 
-		configure_election_start_and_end_time();
 		set_current_time(TIME_DURING_ELECTION);
 
 		assert_noop!(give_vote(who(ALICE), BOB), Error::<Test>::NotRegistered);
@@ -174,9 +198,9 @@ fn test_raise_error_when_voting_by_not_registered_voter() {
 #[test]
 fn test_raise_error_when_voting_to_not_registered_candidate() {
 	ExtBuilder::default().build().execute_with(|| {
+		configure_election_start_and_end_time();
 		register_voter(who(ALICE));
 
-		configure_election_start_and_end_time();
 		set_current_time(TIME_DURING_ELECTION);
 
 		assert_noop!(give_vote(who(ALICE), BOB), Error::<Test>::NotRegistered);
@@ -190,6 +214,2051 @@ fn test_raise_error_when_calling_winner_before_election_end() {
 		set_current_time(TIME_DURING_ELECTION);
 
 		assert_noop!(who_won_elections(), Error::<Test>::ElectionNotEnded);
+
+		// Ballots are still taken in the last block, so it cannot be counted yet
+		set_current_time(ELECTION_END_TIME);
+		assert_noop!(who_won_elections(), Error::<Test>::ElectionNotEnded);
+		assert_ok!(give_vote(who(ALICE), BOB));
+	})
+}
+
+#[test]
+fn test_concurrent_elections_keep_separate_candidates_and_results() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_current_time(TIME_BEFORE_ELECTION);
+		configure_election_start_and_end_time();
+		configure_election_start_and_end_time();
+		System::assert_last_event(Event::ElectionCreated { election_id: OTHER_ELECTION_ID }.into());
+
+		register_voters(&[ALICE, BOB]);
+		register_candidates(&[DAVE, RON]);
+
+		register_candidate_in(who(JOHN), OTHER_ELECTION_ID);
+
+		set_current_time(TIME_DURING_ELECTION);
+
+		give_vote(who(ALICE), DAVE);
+		give_vote(who(BOB), DAVE);
+
+		// ALICE still has a vote to cast in the other election
+		assert_ok!(TemplateModule::give_vote(who(ALICE), OTHER_ELECTION_ID, JOHN));
+		// DAVE only stands in the first election
+		assert_noop!(
+			TemplateModule::give_vote(who(ALICE), OTHER_ELECTION_ID, DAVE),
+			Error::<Test>::NotRegistered
+		);
+
+		set_current_time(TIME_AFTER_ELECTION);
+
+		assert_ok!(who_won_elections());
+		assert_ok!(TemplateModule::winner(who(ALICE), OTHER_ELECTION_ID, 1));
+
+		assert_eq!(TemplateModule::max_votes(ELECTION_ID), 2);
+		assert_eq!(TemplateModule::max_votes(OTHER_ELECTION_ID), 1);
+		assert_eq!(
+			TemplateModule::max_votes_candidate(ELECTION_ID).unwrap().into_inner(),
+			vec![DAVE]
+		);
+		assert_eq!(
+			TemplateModule::max_votes_candidate(OTHER_ELECTION_ID).unwrap().into_inner(),
+			vec![JOHN]
+		);
+	})
+}
+
+#[test]
+fn test_registered_voter_votes_again_in_later_election() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_for_one_voter_one_candidate_and_election_time();
+		set_current_time(TIME_DURING_ELECTION);
+		assert_ok!(give_vote(who(ALICE), BOB));
+
+		set_current_time(TIME_AFTER_ELECTION);
+		assert_ok!(TemplateModule::create_election(
+			root_user(),
+			TIME_AFTER_ELECTION + 1,
+			TIME_AFTER_ELECTION + 10,
+			None,
+			Default::default(),
+			Default::default(),
+		));
+		assert_ok!(register_candidate_in(who(BOB), OTHER_ELECTION_ID));
+		set_current_time(TIME_AFTER_ELECTION + 1);
+
+		// No re-registration needed, and the earlier ballot does not block this one
+		assert_ok!(TemplateModule::give_vote(who(ALICE), OTHER_ELECTION_ID, BOB));
+
+		assert_eq!(TemplateModule::voter_account(ELECTION_ID, ALICE).unwrap(), VoterInfo::voted());
+		assert_eq!(
+			TemplateModule::voter_account(OTHER_ELECTION_ID, ALICE).unwrap(),
+			VoterInfo::voted()
+		);
+		assert_noop!(
+			TemplateModule::give_vote(who(ALICE), OTHER_ELECTION_ID, BOB),
+			Error::<Test>::AlreadyVoted
+		);
+	})
+}
+
+#[test]
+fn test_candidate_registers_with_profile() {
+	ExtBuilder::default().build().execute_with(|| {
+		configure_election_start_and_end_time();
+		set_current_time(TIME_BEFORE_ELECTION);
+
+		let name: ProfileText = b"Bob Smith".to_vec().try_into().unwrap();
+		let party: ProfileText = b"Independent".to_vec().try_into().unwrap();
+		let manifesto = H256::repeat_byte(7);
+
+		assert_ok!(TemplateModule::register_candidate(
+			who(BOB),
+			ELECTION_ID,
+			name.clone(),
+			Some(party.clone()),
+			manifesto
+		));
+		assert_eq!(
+			TemplateModule::candidate_account(ELECTION_ID, BOB).unwrap(),
+			CandidateInfo::new(name, Some(party), manifesto)
+		);
+
+		assert_noop!(
+			TemplateModule::register_candidate(
+				who(DAVE),
+				ELECTION_ID,
+				Default::default(),
+				None,
+				manifesto
+			),
+			Error::<Test>::EmptyCandidateName
+		);
+	})
+}
+
+#[test]
+fn test_candidate_updates_profile_before_voting_starts() {
+	ExtBuilder::default().build().execute_with(|| {
+		configure_election_start_and_end_time();
+		register_candidate(who(BOB));
+		set_current_time(TIME_BEFORE_ELECTION);
+
+		let name: ProfileText = b"Robert".to_vec().try_into().unwrap();
+		let party: ProfileText = b"Green".to_vec().try_into().unwrap();
+		let manifesto = H256::repeat_byte(1);
+
+		assert_ok!(TemplateModule::update_candidate_profile(
+			who(BOB),
+			ELECTION_ID,
+			name.clone(),
+			Some(party.clone()),
+			manifesto
+		));
+		assert_eq!(
+			TemplateModule::candidate_account(ELECTION_ID, BOB).unwrap(),
+			CandidateInfo::new(name.clone(), Some(party), manifesto)
+		);
+		System::assert_last_event(
+			Event::CandidateProfileUpdated { election_id: ELECTION_ID, candidate: BOB }.into(),
+		);
+
+		assert_noop!(
+			TemplateModule::update_candidate_profile(
+				who(DAVE),
+				ELECTION_ID,
+				name.clone(),
+				None,
+				manifesto
+			),
+			Error::<Test>::NotRegistered
+		);
+
+		set_current_time(TIME_DURING_ELECTION);
+		assert_noop!(
+			TemplateModule::update_candidate_profile(who(BOB), ELECTION_ID, name, None, manifesto),
+			Error::<Test>::ElectionStarted
+		);
+	})
+}
+
+#[test]
+fn test_commit_reveal_only_tallies_revealed_ballots() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_current_time(TIME_BEFORE_ELECTION);
+		configure_commit_reveal_election();
+		register_voters(&[ALICE, BOB, JOHN]);
+		register_candidates(&[DAVE, RON]);
+
+		set_current_time(TIME_DURING_ELECTION);
+		assert_noop!(give_vote(who(ALICE), DAVE), Error::<Test>::SecretBallotRequired);
+
+		assert_ok!(commit_vote(who(ALICE), DAVE, [1; 32]));
+		assert_ok!(commit_vote(who(BOB), RON, [2; 32]));
+		assert_ok!(commit_vote(who(JOHN), RON, [3; 32]));
+		System::assert_last_event(Event::VoteCommitted { election_id: ELECTION_ID }.into());
+		assert_noop!(commit_vote(who(ALICE), RON, [1; 32]), Error::<Test>::AlreadyVoted);
+
+		// Nothing is counted while the ballots are sealed
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, DAVE).unwrap().vote_count(), 0);
+		assert_eq!(TemplateModule::unrevealed_votes(ELECTION_ID), 3);
+		assert_noop!(reveal_vote(who(ALICE), DAVE, [1; 32]), Error::<Test>::RevealNotStarted);
+
+		set_current_time(TIME_DURING_REVEAL);
+		assert_noop!(reveal_vote(who(ALICE), RON, [1; 32]), Error::<Test>::CommitmentMismatch);
+		assert_noop!(reveal_vote(who(DAVE), DAVE, [1; 32]), Error::<Test>::NoCommitment);
+
+		assert_ok!(reveal_vote(who(ALICE), DAVE, [1; 32]));
+		assert_ok!(reveal_vote(who(BOB), RON, [2; 32]));
+		System::assert_last_event(Event::VoteRevealed { election_id: ELECTION_ID }.into());
+		assert_noop!(reveal_vote(who(BOB), RON, [2; 32]), Error::<Test>::AlreadyRevealed);
+		assert_noop!(who_won_elections(), Error::<Test>::RevealNotEnded);
+
+		set_current_time(TIME_AFTER_ELECTION);
+		assert_noop!(reveal_vote(who(JOHN), RON, [3; 32]), Error::<Test>::RevealEnded);
+
+		assert_ok!(who_won_elections());
+		assert_eq!(TemplateModule::max_votes(ELECTION_ID), 1);
+		assert_eq!(
+			TemplateModule::max_votes_candidate(ELECTION_ID).unwrap().into_inner(),
+			vec![DAVE, RON]
+		);
+		assert_eq!(TemplateModule::unrevealed_votes(ELECTION_ID), 1);
+		System::assert_has_event(
+			Event::UnrevealedVotesReported { election_id: ELECTION_ID, count: 1 }.into(),
+		);
+	})
+}
+
+#[test]
+fn test_copied_commitment_cannot_be_revealed_by_another_voter() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_current_time(TIME_BEFORE_ELECTION);
+		configure_commit_reveal_election();
+		register_voters(&[ALICE, BOB]);
+		register_candidates(&[DAVE]);
+
+		// BOB copies ALICE's commitment, and later sees her salt when she reveals it
+		set_current_time(TIME_DURING_ELECTION);
+		let commitment = TemplateModule::ballot_commitment(ELECTION_ID, &ALICE, &DAVE, &[1; 32]);
+		assert_ok!(TemplateModule::commit_vote(who(ALICE), ELECTION_ID, commitment));
+		assert_ok!(TemplateModule::commit_vote(who(BOB), ELECTION_ID, commitment));
+
+		set_current_time(TIME_DURING_REVEAL);
+		assert_ok!(reveal_vote(who(ALICE), DAVE, [1; 32]));
+		assert_noop!(reveal_vote(who(BOB), DAVE, [1; 32]), Error::<Test>::CommitmentMismatch);
+	})
+}
+
+#[test]
+fn test_raise_error_when_committing_in_open_election() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_for_one_voter_one_candidate_and_election_time();
+		set_current_time(TIME_DURING_ELECTION);
+
+		assert_noop!(commit_vote(who(ALICE), BOB, [0; 32]), Error::<Test>::SecretBallotNotEnabled);
+	})
+}
+
+#[test]
+fn test_instant_runoff_eliminates_lowest_candidate_each_round() {
+	let ballots = vec![vec![1, 2], vec![1], vec![2, 1], vec![3, 2], vec![2]];
+	let outcome = tally::instant_runoff(&[1, 2, 3], &ballots);
+
+	assert_eq!(outcome.rounds, vec![vec![(1, 2), (2, 2), (3, 1)], vec![(1, 2), (2, 3)]]);
+	assert_eq!(outcome.winners, vec![2]);
+
+	// Nobody can be eliminated when everyone is tied
+	let outcome = tally::instant_runoff(&[1, 2], &[vec![1], vec![2]]);
+	assert_eq!(outcome.winners, vec![1, 2]);
+}
+
+#[test]
+fn test_ranked_ballots_decide_winner_by_instant_runoff() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_current_time(TIME_BEFORE_ELECTION);
+		configure_election_with(VotingMethod::InstantRunoff);
+		register_voters(&[ALICE, BOB, DAVE, JOHN, RON]);
+		register_candidates(&[DAVE, JOHN, RON]);
+
+		set_current_time(TIME_DURING_ELECTION);
+		assert_ok!(give_ranked_vote(who(ALICE), &[DAVE, RON]));
+		assert_ok!(give_ranked_vote(who(BOB), &[DAVE]));
+		assert_ok!(give_ranked_vote(who(DAVE), &[RON, DAVE]));
+		assert_ok!(give_ranked_vote(who(JOHN), &[JOHN, RON]));
+		assert_ok!(give_ranked_vote(who(RON), &[RON]));
+		System::assert_last_event(Event::RankedVoteSuccess { election_id: ELECTION_ID }.into());
+
+		// First preferences alone would be a tie between DAVE and RON
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, DAVE).unwrap().vote_count(), 2);
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, RON).unwrap().vote_count(), 2);
+
+		set_current_time(TIME_AFTER_ELECTION);
+		assert_ok!(who_won_elections());
+		assert_eq!(
+			TemplateModule::max_votes_candidate(ELECTION_ID).unwrap().into_inner(),
+			vec![RON]
+		);
+
+		let first_round = TemplateModule::irv_round(ELECTION_ID, 0).unwrap();
+		assert_eq!(first_round.len(), 3);
+		assert!(first_round.contains(&(JOHN, 1)));
+		let second_round = TemplateModule::irv_round(ELECTION_ID, 1).unwrap();
+		assert_eq!(second_round.len(), 2);
+		assert!(second_round.contains(&(RON, 3)));
+		assert!(second_round.contains(&(DAVE, 2)));
+		assert_eq!(TemplateModule::irv_round(ELECTION_ID, 2), None);
+	})
+}
+
+#[test]
+fn test_raise_error_for_invalid_ranked_ballots() {
+	ExtBuilder::default().build().execute_with(|| {
+		configure_election_with(VotingMethod::InstantRunoff);
+		register_voters(&[ALICE]);
+		register_candidates(&[DAVE, RON]);
+		set_current_time(TIME_DURING_ELECTION);
+
+		assert_noop!(give_vote(who(ALICE), DAVE), Error::<Test>::WrongBallotType);
+		assert_noop!(give_ranked_vote(who(ALICE), &[]), Error::<Test>::EmptyBallot);
+		assert_noop!(give_ranked_vote(who(ALICE), &[DAVE, BOB]), Error::<Test>::NotRegistered);
+		assert_noop!(
+			give_ranked_vote(who(ALICE), &[DAVE, RON, DAVE]),
+			Error::<Test>::DuplicateCandidate
+		);
+		assert_noop!(give_ranked_vote(who(BOB), &[DAVE]), Error::<Test>::NotRegistered);
+
+		assert_ok!(give_ranked_vote(who(ALICE), &[RON, DAVE]));
+		assert_noop!(give_ranked_vote(who(ALICE), &[DAVE]), Error::<Test>::AlreadyVoted);
+	})
+}
+
+#[test]
+fn test_raise_error_for_ranked_ballot_in_plurality_election() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_for_one_voter_one_candidate_and_election_time();
+		set_current_time(TIME_DURING_ELECTION);
+
+		assert_noop!(give_ranked_vote(who(ALICE), &[BOB]), Error::<Test>::WrongBallotType);
+		assert_noop!(
+			TemplateModule::create_election(
+				root_user(),
+				ELECTION_START_TIME,
+				ELECTION_END_TIME,
+				Some(REVEAL_END_TIME),
+				ElectionSettings { method: VotingMethod::InstantRunoff, ..Default::default() },
+				Default::default(),
+			),
+			Error::<Test>::SecretBallotNotSupported
+		);
+	})
+}
+
+#[test]
+fn test_approval_ballot_gives_every_approved_candidate_a_vote() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_current_time(TIME_BEFORE_ELECTION);
+		configure_election_with(VotingMethod::Approval);
+		register_voters(&[ALICE, BOB, JOHN]);
+		register_candidates(&[DAVE, JOHN, RON]);
+
+		set_current_time(TIME_DURING_ELECTION);
+		assert_noop!(give_vote(who(ALICE), DAVE), Error::<Test>::WrongBallotType);
+		assert_noop!(
+			give_approval_vote(who(ALICE), &[DAVE, DAVE]),
+			Error::<Test>::DuplicateCandidate
+		);
+
+		assert_ok!(give_approval_vote(who(ALICE), &[DAVE, RON]));
+		assert_ok!(give_approval_vote(who(BOB), &[RON]));
+		assert_ok!(give_approval_vote(who(JOHN), &[JOHN, RON, DAVE]));
+		System::assert_last_event(Event::ApprovalVoteSuccess { election_id: ELECTION_ID }.into());
+		assert_noop!(give_approval_vote(who(ALICE), &[JOHN]), Error::<Test>::AlreadyVoted);
+
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, DAVE).unwrap().vote_count(), 2);
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, JOHN).unwrap().vote_count(), 1);
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, RON).unwrap().vote_count(), 3);
+		assert_eq!(
+			TemplateModule::approval_ballot(ELECTION_ID, ALICE).unwrap().into_inner(),
+			vec![DAVE, RON]
+		);
+
+		set_current_time(TIME_AFTER_ELECTION);
+		assert_ok!(who_won_elections());
+		assert_eq!(TemplateModule::max_votes(ELECTION_ID), 3);
+		assert_eq!(
+			TemplateModule::max_votes_candidate(ELECTION_ID).unwrap().into_inner(),
+			vec![RON]
+		);
+	})
+}
+
+#[test]
+fn test_star_runoff_prefers_finalist_scored_higher_on_more_ballots() {
+	let totals = [(1, 10), (2, 9), (3, 4)];
+	let ballots = vec![vec![(1, 5), (2, 0)], vec![(1, 5), (2, 4)], vec![(2, 5), (1, 0)]];
+	assert_eq!(
+		tally::star(&totals, &ballots),
+		tally::StarOutcome { finalists: vec![(1, 2), (2, 1)], winners: vec![1] }
+	);
+
+	// Equal preference goes to the higher total, and equal totals to both finalists
+	let ballots = vec![vec![(1, 1)], vec![(2, 1)]];
+	assert_eq!(tally::star(&totals, &ballots).winners, vec![1]);
+	assert_eq!(tally::star(&[(1, 3), (2, 3)], &ballots).winners, vec![1, 2]);
+}
+
+#[test]
+fn test_score_ballots_sum_into_vote_count() {
+	ExtBuilder::default().build().execute_with(|| {
+		configure_election_with_settings(ElectionSettings {
+			method: VotingMethod::Score,
+			max_score: 5,
+			..Default::default()
+		});
+		register_voters(&[ALICE, BOB]);
+		register_candidates(&[DAVE, RON]);
+
+		set_current_time(TIME_DURING_ELECTION);
+		assert_noop!(give_vote(who(ALICE), DAVE), Error::<Test>::WrongBallotType);
+		assert_noop!(give_score_vote(who(ALICE), &[(DAVE, 6)]), Error::<Test>::ScoreOutOfRange);
+
+		assert_ok!(give_score_vote(who(ALICE), &[(DAVE, 5), (RON, 3)]));
+		assert_ok!(give_score_vote(who(BOB), &[(RON, 4)]));
+		System::assert_last_event(Event::ScoreVoteSuccess { election_id: ELECTION_ID }.into());
+
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, DAVE).unwrap().vote_count(), 5);
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, RON).unwrap().vote_count(), 7);
+
+		set_current_time(TIME_AFTER_ELECTION);
+		assert_ok!(who_won_elections());
+		assert_eq!(
+			TemplateModule::max_votes_candidate(ELECTION_ID).unwrap().into_inner(),
+			vec![RON]
+		);
+		assert_eq!(TemplateModule::star_finalists(ELECTION_ID), None);
+	})
+}
+
+#[test]
+fn test_star_election_runs_automatic_runoff() {
+	ExtBuilder::default().build().execute_with(|| {
+		configure_election_with_settings(ElectionSettings {
+			method: VotingMethod::Star,
+			max_score: 5,
+			..Default::default()
+		});
+		register_voters(&[ALICE, BOB, DAVE, JOHN, RON]);
+		register_candidates(&[DAVE, JOHN, RON]);
+
+		set_current_time(TIME_DURING_ELECTION);
+		assert_ok!(give_score_vote(who(ALICE), &[(DAVE, 5)]));
+		assert_ok!(give_score_vote(who(BOB), &[(DAVE, 5)]));
+		assert_ok!(give_score_vote(who(JOHN), &[(RON, 4), (DAVE, 3)]));
+		assert_ok!(give_score_vote(who(DAVE), &[(RON, 4), (DAVE, 3)]));
+		assert_ok!(give_score_vote(who(RON), &[(RON, 4), (DAVE, 3), (JOHN, 5)]));
+
+		// DAVE has the highest total, but more voters prefer RON over DAVE
+		assert_eq!(TemplateModule::max_votes(ELECTION_ID), 19);
+
+		set_current_time(TIME_AFTER_ELECTION);
+		assert_ok!(who_won_elections());
+		assert_eq!(
+			TemplateModule::max_votes_candidate(ELECTION_ID).unwrap().into_inner(),
+			vec![RON]
+		);
+		assert_eq!(
+			TemplateModule::star_finalists(ELECTION_ID).unwrap().into_inner(),
+			vec![(DAVE, 2), (RON, 3)]
+		);
+	})
+}
+
+#[test]
+fn test_raise_error_when_score_election_has_no_scale() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			configure_election_with_settings(ElectionSettings {
+				method: VotingMethod::Score,
+				max_score: 0,
+				..Default::default()
+			}),
+			Error::<Test>::InvalidScoreScale
+		);
+	})
+}
+
+#[test]
+fn test_stv_transfers_surplus_then_eliminates_lowest() {
+	let ballots = vec![vec![1, 3], vec![1, 3], vec![1, 2], vec![2], vec![3]];
+	let outcome = tally::stv(&[1, 2, 3], &ballots, 2);
+	assert_eq!(outcome.elected, vec![1, 3]);
+
+	// Quota is 2 votes, so 1 of candidate 1's 3 votes is passed on at a third of a vote each
+	assert_eq!(
+		outcome.rounds,
+		vec![
+			tally::StvRound {
+				counts: vec![(1, 3_000_000), (2, 1_000_000), (3, 1_000_000)],
+				action: tally::StvAction::Elected { candidate: 1, surplus: 1_000_000 },
+			},
+			tally::StvRound {
+				counts: vec![(2, 1_333_333), (3, 1_666_666)],
+				action: tally::StvAction::Eliminated { candidate: 2 },
+			},
+			tally::StvRound {
+				counts: vec![(3, 1_666_666)],
+				action: tally::StvAction::ElectedWithoutQuota { candidate: 3 },
+			},
+		]
+	);
+}
+
+#[test]
+fn test_stv_election_fills_seats_and_logs_rounds() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(configure_election_with_settings(ElectionSettings {
+			method: VotingMethod::Stv,
+			seats: 2,
+			..Default::default()
+		}));
+		register_voters(&[ALICE, BOB, DAVE, JOHN, RON]);
+		register_candidates(&[DAVE, RON, JOHN]);
+
+		set_current_time(TIME_DURING_ELECTION);
+		assert_ok!(give_ranked_vote(who(ALICE), &[DAVE, JOHN]));
+		assert_ok!(give_ranked_vote(who(BOB), &[DAVE, JOHN]));
+		assert_ok!(give_ranked_vote(who(DAVE), &[DAVE, RON]));
+		assert_ok!(give_ranked_vote(who(JOHN), &[RON]));
+		assert_ok!(give_ranked_vote(who(RON), &[JOHN]));
+
+		set_current_time(TIME_AFTER_ELECTION);
+		assert_ok!(who_won_elections());
+		assert_eq!(
+			TemplateModule::max_votes_candidate(ELECTION_ID).unwrap().into_inner(),
+			vec![DAVE, JOHN]
+		);
+
+		let first = TemplateModule::stv_round(ELECTION_ID, 0).unwrap();
+		assert_eq!(first.action, tally::StvAction::Elected { candidate: DAVE, surplus: 1_000_000 });
+		let second = TemplateModule::stv_round(ELECTION_ID, 1).unwrap();
+		assert_eq!(second.action, tally::StvAction::Eliminated { candidate: RON });
+		assert_eq!(second.counts.into_inner(), vec![(RON, 1_333_333), (JOHN, 1_666_666)]);
+		assert!(TemplateModule::stv_round(ELECTION_ID, 3).is_none());
+
+		// The rounds go with the rest of the count once the election is purged
+		assert_ok!(TemplateModule::purge_election(who(ALICE), ELECTION_ID, 100));
+		assert!(TemplateModule::stv_round(ELECTION_ID, 0).is_none());
+	})
+}
+
+#[test]
+fn test_raise_error_when_stv_seat_count_invalid() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			configure_election_with_settings(ElectionSettings {
+				method: VotingMethod::Stv,
+				seats: 0,
+				..Default::default()
+			}),
+			Error::<Test>::InvalidSeatCount
+		);
+		assert_noop!(
+			configure_election_with_settings(ElectionSettings {
+				method: VotingMethod::Stv,
+				seats: 101,
+				..Default::default()
+			}),
+			Error::<Test>::InvalidSeatCount
+		);
+	})
+}
+
+#[test]
+fn test_borda_and_schulze_count_ranked_ballots() {
+	let mut ballots = vec![vec![1, 2, 3]; 3];
+	ballots.extend(vec![vec![2, 3, 1]; 2]);
+	ballots.extend(vec![vec![3, 1, 2]; 2]);
+
+	let borda = tally::borda(&[1, 2, 3], &ballots);
+	assert_eq!(borda.totals, vec![(1, 8), (2, 7), (3, 6)]);
+	assert_eq!(borda.winners, vec![1]);
+
+	// 1 beats 2, 2 beats 3 and 3 beats 1, so there is no Condorcet winner, but the path from 1
+	// through 2 to 3 is stronger than the direct defeat by 3
+	let schulze = tally::schulze(&[1, 2, 3], &ballots);
+	assert_eq!(schulze.pairwise, vec![vec![0, 5, 3], vec![2, 0, 5], vec![4, 2, 0]]);
+	assert_eq!(schulze.winners, vec![1]);
+	assert_eq!(tally::condorcet_winner(&[1, 2, 3], &schulze.pairwise), None);
+
+	// A ranked candidate is preferred to every unranked one
+	let ballots = vec![vec![1, 2], vec![2, 1], vec![2, 3]];
+	let matrix = tally::pairwise(&[1, 2, 3], &ballots);
+	assert_eq!(tally::condorcet_winner(&[1, 2, 3], &matrix), Some(2));
+}
+
+fn cast_borda_split_ballots() {
+	register_voters(&[ALICE, BOB, DAVE, JOHN, RON]);
+	register_candidates(&[DAVE, RON, JOHN]);
+
+	set_current_time(TIME_DURING_ELECTION);
+	assert_ok!(give_ranked_vote(who(ALICE), &[DAVE, RON, JOHN]));
+	assert_ok!(give_ranked_vote(who(BOB), &[DAVE, RON, JOHN]));
+	assert_ok!(give_ranked_vote(who(DAVE), &[DAVE, RON, JOHN]));
+	assert_ok!(give_ranked_vote(who(JOHN), &[RON, JOHN, DAVE]));
+	assert_ok!(give_ranked_vote(who(RON), &[RON, JOHN, DAVE]));
+}
+
+#[test]
+fn test_borda_election_can_differ_from_condorcet_winner() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(configure_election_with(VotingMethod::Borda));
+		cast_borda_split_ballots();
+
+		set_current_time(TIME_AFTER_ELECTION);
+		assert_ok!(who_won_elections());
+		assert_eq!(
+			TemplateModule::max_votes_candidate(ELECTION_ID).unwrap().into_inner(),
+			vec![RON]
+		);
+		assert_eq!(TemplateModule::borda_score(ELECTION_ID, DAVE), 6);
+		assert_eq!(TemplateModule::borda_score(ELECTION_ID, RON), 7);
+		assert_eq!(TemplateModule::borda_score(ELECTION_ID, JOHN), 2);
+
+		// DAVE is still preferred to each other candidate by a majority
+		assert_eq!(TemplateModule::condorcet_winner(ELECTION_ID), Some(DAVE));
+		System::assert_has_event(
+			Event::CondorcetWinnerFound { election_id: ELECTION_ID, candidate: DAVE }.into(),
+		);
+	})
+}
+
+#[test]
+fn test_schulze_election_stores_pairwise_preferences() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(configure_election_with(VotingMethod::Schulze));
+		cast_borda_split_ballots();
+
+		set_current_time(TIME_AFTER_ELECTION);
+		assert_ok!(who_won_elections());
+		assert_eq!(
+			TemplateModule::max_votes_candidate(ELECTION_ID).unwrap().into_inner(),
+			vec![DAVE]
+		);
+		assert_eq!(TemplateModule::pairwise_preference(ELECTION_ID, (DAVE, RON)), 3);
+		assert_eq!(TemplateModule::pairwise_preference(ELECTION_ID, (RON, DAVE)), 2);
+		assert_eq!(TemplateModule::pairwise_preference(ELECTION_ID, (RON, JOHN)), 5);
+		assert_eq!(TemplateModule::condorcet_winner(ELECTION_ID), Some(DAVE));
+	})
+}
+
+#[test]
+fn test_raise_error_when_winner_is_given_too_few_ballots() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(configure_election_with(VotingMethod::Schulze));
+		cast_borda_split_ballots();
+
+		set_current_time(TIME_AFTER_ELECTION);
+		assert_noop!(
+			TemplateModule::winner(who(ALICE), ELECTION_ID, 4),
+			Error::<Test>::BallotCountTooLow
+		);
+		assert_ok!(TemplateModule::winner(who(ALICE), ELECTION_ID, 5));
+	})
+}
+
+#[test]
+fn test_custom_tally_method_from_runtime_counts_election() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(configure_election_with(VotingMethod::Custom(FEWEST_FIRST_PREFERENCES)));
+		register_voters(&[ALICE, BOB, DAVE]);
+		register_candidates(&[DAVE, RON]);
+
+		set_current_time(TIME_DURING_ELECTION);
+		assert_ok!(give_ranked_vote(who(ALICE), &[DAVE, RON]));
+		assert_ok!(give_ranked_vote(who(BOB), &[DAVE]));
+		assert_ok!(give_ranked_vote(who(DAVE), &[RON]));
+
+		set_current_time(TIME_AFTER_ELECTION);
+		assert_ok!(who_won_elections());
+		assert_eq!(
+			TemplateModule::max_votes_candidate(ELECTION_ID).unwrap().into_inner(),
+			vec![RON]
+		);
+	})
+}
+
+#[test]
+fn test_raise_error_when_tally_method_not_offered_by_runtime() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			configure_election_with(VotingMethod::Custom(FEWEST_FIRST_PREFERENCES + 1)),
+			Error::<Test>::UnsupportedTallyMethod
+		);
+	})
+}
+
+#[test]
+fn test_quadratic_votes_cost_square_of_total_per_candidate() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(configure_election_with_settings(ElectionSettings {
+			method: VotingMethod::Quadratic,
+			credits: 20,
+			..Default::default()
+		}));
+		register_voters(&[ALICE, BOB]);
+		register_candidates(&[DAVE, RON]);
+
+		set_current_time(TIME_DURING_ELECTION);
+		assert_noop!(give_ranked_vote(who(ALICE), &[DAVE]), Error::<Test>::WrongBallotType);
+		assert_noop!(give_votes(who(ALICE), DAVE, 0), Error::<Test>::EmptyBallot);
+		assert_noop!(give_votes(who(ALICE), DAVE, 5), Error::<Test>::InsufficientCredits);
+
+		// 3 votes cost 9, and a fourth vote for the same candidate costs 16 - 9 = 7 more
+		assert_ok!(give_votes(who(ALICE), DAVE, 3));
+		System::assert_last_event(
+			Event::QuadraticVoteSuccess { election_id: ELECTION_ID, candidate: DAVE, votes: 3 }
+				.into(),
+		);
+		assert_ok!(give_votes(who(ALICE), DAVE, 1));
+		assert_eq!(TemplateModule::voter_account(ELECTION_ID, ALICE).unwrap().credits_spent(), 16);
+
+		// 2 votes for another candidate cost 4, which uses up the budget exactly
+		assert_noop!(give_votes(who(ALICE), RON, 3), Error::<Test>::InsufficientCredits);
+		assert_ok!(give_votes(who(ALICE), RON, 2));
+		assert_noop!(give_votes(who(ALICE), RON, 1), Error::<Test>::InsufficientCredits);
+		assert_eq!(
+			TemplateModule::quadratic_ballot(ELECTION_ID, ALICE).unwrap().into_inner(),
+			vec![(DAVE, 4), (RON, 2)]
+		);
+
+		assert_ok!(give_votes(who(BOB), RON, 4));
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, DAVE).unwrap().vote_count(), 4);
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, RON).unwrap().vote_count(), 6);
+
+		set_current_time(TIME_AFTER_ELECTION);
+		assert_ok!(who_won_elections());
+		assert_eq!(
+			TemplateModule::max_votes_candidate(ELECTION_ID).unwrap().into_inner(),
+			vec![RON]
+		);
+	})
+}
+
+#[test]
+fn test_raise_error_when_quadratic_election_has_no_budget() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			configure_election_with_settings(ElectionSettings {
+				method: VotingMethod::Quadratic,
+				credits: 0,
+				..Default::default()
+			}),
+			Error::<Test>::InvalidCreditBudget
+		);
+	})
+}
+
+#[test]
+fn test_token_weighted_ballot_locks_counted_balance_until_election_ends() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(configure_election_with_settings(ElectionSettings {
+			weighting: VoteWeighting::FreeBalance,
+			..Default::default()
+		}));
+		register_voters(&[ALICE, BOB, RON, JOHN]);
+		register_candidates(&[DAVE, RON]);
+
+		set_current_time(TIME_DURING_ELECTION);
+		assert_ok!(give_vote(who(ALICE), DAVE));
+		assert_ok!(give_vote(who(BOB), RON));
+		assert_noop!(give_vote(who(JOHN), RON), Error::<Test>::NoVotingWeight);
+
+		// The counted balance cannot be handed to another voter and counted again
+		assert_noop!(
+			Balances::transfer(who(ALICE), RON, 60),
+			pallet_balances::Error::<Test>::LiquidityRestrictions
+		);
+		assert_ok!(give_vote(who(RON), RON));
+		assert_eq!(TemplateModule::voter_account(ELECTION_ID, ALICE).unwrap().weight(), 100);
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, DAVE).unwrap().vote_count(), 100);
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, RON).unwrap().vote_count(), 70);
+		assert_noop!(
+			TemplateModule::unlock(who(ALICE), ELECTION_ID),
+			Error::<Test>::LockNotExpired
+		);
+
+		set_current_time(TIME_AFTER_ELECTION);
+		assert_ok!(who_won_elections());
+		assert_eq!(TemplateModule::max_votes(ELECTION_ID), 100);
+		assert_eq!(
+			TemplateModule::max_votes_candidate(ELECTION_ID).unwrap().into_inner(),
+			vec![DAVE]
+		);
+		assert_ok!(TemplateModule::unlock(who(ALICE), ELECTION_ID));
+		assert_ok!(Balances::transfer(who(ALICE), RON, 60));
+	})
+}
+
+#[test]
+fn test_token_weighted_ballot_can_count_reserved_balance() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(configure_election_with_settings(ElectionSettings {
+			weighting: VoteWeighting::ReservedBalance,
+			..Default::default()
+		}));
+		register_voters(&[ALICE, BOB]);
+		register_candidates(&[DAVE]);
+		assert_ok!(<Balances as ReservableCurrency<AccountId>>::reserve(&BOB, 40));
+
+		set_current_time(TIME_DURING_ELECTION);
+		assert_noop!(give_vote(who(ALICE), DAVE), Error::<Test>::NoVotingWeight);
+		assert_ok!(give_vote(who(BOB), DAVE));
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, DAVE).unwrap().vote_count(), 40);
+	})
+}
+
+#[test]
+fn test_raise_error_when_weighting_non_plurality_election() {
+	ExtBuilder::default().build().execute_with(|| {
+		let settings = ElectionSettings {
+			method: VotingMethod::Approval,
+			weighting: VoteWeighting::FreeBalance,
+			..Default::default()
+		};
+		assert_noop!(
+			TemplateModule::create_election(
+				root_user(),
+				ELECTION_START_TIME,
+				ELECTION_END_TIME,
+				None,
+				settings,
+				Default::default(),
+			),
+			Error::<Test>::WeightingNotSupported
+		);
+	})
+}
+
+#[test]
+fn test_conviction_vote_multiplies_weight_and_locks_balance() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_current_time(1);
+		assert_ok!(configure_election_with_settings(ElectionSettings {
+			weighting: VoteWeighting::FreeBalance,
+			conviction: true,
+			..Default::default()
+		}));
+		register_voters(&[ALICE, BOB]);
+		register_candidates(&[DAVE, RON]);
+
+		set_current_time(TIME_DURING_ELECTION);
+		assert_ok!(give_conviction_vote(who(ALICE), DAVE, Conviction::Locked2x));
+		System::assert_last_event(
+			Event::ConvictionVoteSuccess {
+				election_id: ELECTION_ID,
+				conviction: Conviction::Locked2x,
+				until: ELECTION_END_TIME + 2 * 10,
+			}
+			.into(),
+		);
+		assert_ok!(give_conviction_vote(who(BOB), RON, Conviction::None));
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, DAVE).unwrap().vote_count(), 200);
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, RON).unwrap().vote_count(), 5);
+
+		// Both balances are locked while voting runs, but only a conviction outlasts it
+		assert_noop!(
+			Balances::transfer(who(ALICE), BOB, 50),
+			pallet_balances::Error::<Test>::LiquidityRestrictions
+		);
+		assert_noop!(
+			Balances::transfer(who(BOB), ALICE, 10),
+			pallet_balances::Error::<Test>::LiquidityRestrictions
+		);
+
+		set_current_time(TIME_AFTER_ELECTION);
+		assert_ok!(TemplateModule::unlock(who(BOB), ELECTION_ID));
+		assert_ok!(Balances::transfer(who(BOB), ALICE, 10));
+		assert_noop!(
+			TemplateModule::unlock(who(ALICE), ELECTION_ID),
+			Error::<Test>::LockNotExpired
+		);
+
+		set_current_time(ELECTION_END_TIME + 2 * 10);
+		assert_ok!(TemplateModule::unlock(who(ALICE), ELECTION_ID));
+		System::assert_last_event(
+			Event::FundsUnlocked { who: ALICE, election_id: ELECTION_ID }.into(),
+		);
+		assert_eq!(TemplateModule::conviction_lock(ALICE, ELECTION_ID), None);
+		assert_ok!(Balances::transfer(who(ALICE), BOB, 50));
+	})
+}
+
+#[test]
+fn test_unlocked_vote_weighs_less_than_a_locked_one() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(configure_election_with_settings(ElectionSettings {
+			weighting: VoteWeighting::FreeBalance,
+			conviction: true,
+			..Default::default()
+		}));
+		register_voters(&[ALICE]);
+		register_candidates(&[DAVE]);
+
+		// Without a conviction, the ballot counts as `Conviction::None`
+		set_current_time(TIME_DURING_ELECTION);
+		assert_noop!(TemplateModule::unlock(who(ALICE), ELECTION_ID), Error::<Test>::NoLock);
+		assert_ok!(give_vote(who(ALICE), DAVE));
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, DAVE).unwrap().vote_count(), 10);
+		assert_eq!(TemplateModule::conviction_lock(ALICE, ELECTION_ID).unwrap().after_end, 0);
+
+		assert_ok!(revoke_vote(who(ALICE)));
+		assert_ok!(give_conviction_vote(who(ALICE), DAVE, Conviction::Locked1x));
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, DAVE).unwrap().vote_count(), 100);
+	})
+}
+
+#[test]
+fn test_raise_error_when_election_does_not_take_conviction_votes() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			configure_election_with_settings(ElectionSettings {
+				conviction: true,
+				..Default::default()
+			}),
+			Error::<Test>::ConvictionNotSupported
+		);
+
+		// Weighting by free balance alone does not take conviction votes
+		assert_ok!(configure_election_with_settings(ElectionSettings {
+			weighting: VoteWeighting::FreeBalance,
+			..Default::default()
+		}));
+		register_voters(&[ALICE]);
+		register_candidates(&[DAVE]);
+
+		set_current_time(TIME_DURING_ELECTION);
+		assert_noop!(
+			give_conviction_vote(who(ALICE), DAVE, Conviction::Locked1x),
+			Error::<Test>::ConvictionNotSupported
+		);
+	})
+}
+
+#[test]
+fn test_delegated_votes_follow_the_chain_until_delegator_votes() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_current_time(1);
+		configure_election_start_and_end_time();
+		register_voters(&[ALICE, BOB, DAVE, RON]);
+		register_candidates(&[JOHN, RON]);
+
+		set_current_time(TIME_DURING_ELECTION);
+		assert_ok!(delegate(who(BOB), ALICE));
+		System::assert_last_event(
+			Event::Delegated { election_id: ELECTION_ID, who: BOB, to: ALICE }.into(),
+		);
+		assert_ok!(delegate(who(DAVE), BOB));
+		assert_ok!(give_vote(who(ALICE), JOHN));
+		assert_ok!(give_vote(who(RON), RON));
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, JOHN).unwrap().vote_count(), 3);
+		assert_eq!(TemplateModule::voter_account(ELECTION_ID, ALICE).unwrap().delegated(), 2);
+
+		// Voting directly takes BOB's vote, and DAVE's with it, back from ALICE
+		assert_ok!(give_vote(who(BOB), RON));
+		assert_eq!(TemplateModule::voter_account(ELECTION_ID, BOB).unwrap().delegate(), None);
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, JOHN).unwrap().vote_count(), 1);
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, RON).unwrap().vote_count(), 3);
+
+		set_current_time(TIME_AFTER_ELECTION);
+		assert_ok!(who_won_elections());
+		assert_eq!(TemplateModule::max_votes(ELECTION_ID), 3);
+		assert_eq!(
+			TemplateModule::max_votes_candidate(ELECTION_ID).unwrap().into_inner(),
+			vec![RON]
+		);
+	})
+}
+
+#[test]
+fn test_undelegate_takes_weight_back_from_the_delegates_candidate() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_current_time(1);
+		assert_ok!(configure_election_with_settings(ElectionSettings {
+			weighting: VoteWeighting::FreeBalance,
+			..Default::default()
+		}));
+		register_voters(&[ALICE, BOB, RON]);
+		register_candidates(&[DAVE, RON]);
+
+		set_current_time(TIME_DURING_ELECTION);
+		assert_ok!(give_vote(who(BOB), DAVE));
+		assert_ok!(give_vote(who(RON), RON));
+		assert_ok!(delegate(who(ALICE), BOB));
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, DAVE).unwrap().vote_count(), 150);
+		assert_eq!(TemplateModule::max_votes(ELECTION_ID), 150);
+
+		assert_ok!(undelegate(who(ALICE)));
+		System::assert_last_event(
+			Event::Undelegated { election_id: ELECTION_ID, who: ALICE }.into(),
+		);
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, DAVE).unwrap().vote_count(), 50);
+		assert_eq!(TemplateModule::max_votes(ELECTION_ID), 50);
+		assert_noop!(undelegate(who(ALICE)), Error::<Test>::NotDelegating);
+
+		// Having taken the vote back, ALICE can cast it directly
+		assert_ok!(give_vote(who(ALICE), RON));
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, RON).unwrap().vote_count(), 120);
+	})
+}
+
+#[test]
+fn test_raise_error_when_delegation_loops_or_runs_too_deep() {
+	ExtBuilder::default().build().execute_with(|| {
+		configure_election_start_and_end_time();
+		register_voters(&[ALICE, BOB, DAVE, RON, JOHN]);
+		register_candidates(&[DAVE]);
+
+		set_current_time(TIME_DURING_ELECTION);
+		assert_noop!(delegate(who(ALICE), ALICE), Error::<Test>::DelegationCycle);
+		assert_ok!(delegate(who(BOB), ALICE));
+		assert_noop!(delegate(who(BOB), DAVE), Error::<Test>::AlreadyDelegating);
+		assert_ok!(delegate(who(DAVE), BOB));
+		assert_ok!(delegate(who(RON), DAVE));
+		assert_noop!(delegate(who(ALICE), RON), Error::<Test>::DelegationCycle);
+
+		// RON -> DAVE -> BOB -> ALICE is already as long as the mock allows
+		assert_noop!(delegate(who(JOHN), RON), Error::<Test>::DelegationTooDeep);
+		assert_noop!(delegate(who(ALICE), JOHN), Error::<Test>::DelegationTooDeep);
+
+		assert_ok!(give_vote(who(JOHN), DAVE));
+		assert_noop!(delegate(who(JOHN), ALICE), Error::<Test>::AlreadyVoted);
+	})
+}
+
+#[test]
+fn test_raise_error_when_delegating_outside_open_plurality_election() {
+	ExtBuilder::default().build().execute_with(|| {
+		configure_election_with(VotingMethod::InstantRunoff);
+		register_voters(&[ALICE, BOB]);
+
+		set_current_time(TIME_DURING_ELECTION);
+		assert_noop!(delegate(who(ALICE), BOB), Error::<Test>::DelegationNotSupported);
+	})
+}
+
+#[test]
+fn test_change_and_revoke_vote_keep_counts_and_max_vote_correct() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_current_time(1);
+		configure_election_start_and_end_time();
+		register_voters(&[ALICE, BOB, DAVE]);
+		register_candidates(&[RON, JOHN]);
+
+		set_current_time(TIME_DURING_ELECTION);
+		assert_ok!(give_vote(who(ALICE), RON));
+		assert_ok!(give_vote(who(BOB), RON));
+		assert_ok!(give_vote(who(DAVE), JOHN));
+
+		assert_ok!(change_vote(who(BOB), JOHN));
+		System::assert_last_event(Event::VoteChanged { election_id: ELECTION_ID, who: BOB }.into());
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, RON).unwrap().vote_count(), 1);
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, JOHN).unwrap().vote_count(), 2);
+		assert_eq!(
+			TemplateModule::voter_account(ELECTION_ID, BOB).unwrap(),
+			VoterInfo::set(true, JOHN)
+		);
+
+		// The leader losing a vote lowers MaxVote
+		assert_ok!(revoke_vote(who(DAVE)));
+		System::assert_last_event(
+			Event::VoteRevoked { election_id: ELECTION_ID, who: DAVE }.into(),
+		);
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, JOHN).unwrap().vote_count(), 1);
+		assert_eq!(TemplateModule::max_votes(ELECTION_ID), 1);
+
+		// A revoked voter can vote again
+		assert_ok!(give_vote(who(DAVE), RON));
+
+		set_current_time(TIME_AFTER_ELECTION);
+		assert_ok!(who_won_elections());
+		assert_eq!(TemplateModule::max_votes(ELECTION_ID), 2);
+		assert_eq!(
+			TemplateModule::max_votes_candidate(ELECTION_ID).unwrap().into_inner(),
+			vec![RON]
+		);
+	})
+}
+
+#[test]
+fn test_changed_vote_carries_delegations_and_revoked_vote_keeps_them() {
+	ExtBuilder::default().build().execute_with(|| {
+		configure_election_start_and_end_time();
+		register_voters(&[ALICE, BOB]);
+		register_candidates(&[RON, JOHN]);
+
+		set_current_time(TIME_DURING_ELECTION);
+		assert_ok!(delegate(who(BOB), ALICE));
+		assert_ok!(give_vote(who(ALICE), RON));
+		assert_ok!(change_vote(who(ALICE), JOHN));
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, RON).unwrap().vote_count(), 0);
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, JOHN).unwrap().vote_count(), 2);
+
+		assert_ok!(revoke_vote(who(ALICE)));
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, JOHN).unwrap().vote_count(), 0);
+		assert_eq!(TemplateModule::voter_account(ELECTION_ID, ALICE).unwrap().delegated(), 1);
+
+		assert_ok!(give_vote(who(ALICE), RON));
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, RON).unwrap().vote_count(), 2);
+	})
+}
+
+#[test]
+fn test_revoked_conviction_vote_keeps_its_lock() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(configure_election_with_settings(ElectionSettings {
+			weighting: VoteWeighting::FreeBalance,
+			conviction: true,
+			..Default::default()
+		}));
+		register_voters(&[ALICE]);
+		register_candidates(&[DAVE]);
+
+		set_current_time(TIME_DURING_ELECTION);
+		assert_ok!(give_conviction_vote(who(ALICE), DAVE, Conviction::Locked2x));
+		assert_ok!(revoke_vote(who(ALICE)));
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, DAVE).unwrap().vote_count(), 0);
+
+		// Voting again with a lower conviction does not shorten the lock
+		assert_ok!(give_conviction_vote(who(ALICE), DAVE, Conviction::Locked1x));
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, DAVE).unwrap().vote_count(), 100);
+		assert_eq!(TemplateModule::conviction_lock(ALICE, ELECTION_ID).unwrap().after_end, 2 * 10);
+	})
+}
+
+#[test]
+fn test_raise_error_when_changing_or_revoking_without_open_vote() {
+	ExtBuilder::default().build().execute_with(|| {
+		configure_election_start_and_end_time();
+		register_voters(&[ALICE, BOB]);
+		register_candidates(&[RON]);
+
+		set_current_time(TIME_DURING_ELECTION);
+		assert_noop!(change_vote(who(ALICE), RON), Error::<Test>::NotVoted);
+		assert_noop!(revoke_vote(who(ALICE)), Error::<Test>::NotVoted);
+
+		assert_ok!(give_vote(who(ALICE), RON));
+		assert_noop!(change_vote(who(ALICE), JOHN), Error::<Test>::NotRegistered);
+		assert_ok!(revoke_vote(who(ALICE)));
+		assert_noop!(revoke_vote(who(ALICE)), Error::<Test>::NotVoted);
+
+		assert_ok!(give_vote(who(BOB), RON));
+		set_current_time(TIME_AFTER_ELECTION);
+		assert_noop!(revoke_vote(who(BOB)), Error::<Test>::ElectionEnded);
+	})
+}
+
+#[test]
+fn test_voter_roll_election_only_admits_voters_the_authority_added() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_current_time(1);
+		assert_ok!(configure_election_with_settings(ElectionSettings {
+			eligibility: Eligibility::VoterRoll,
+			..Default::default()
+		}));
+		register_voters(&[ALICE]);
+		register_candidates(&[JOHN]);
+
+		assert_ok!(add_voters(&[BOB, DAVE]));
+		System::assert_last_event(Event::VotersAdded { count: 2 }.into());
+		// Accounts already on the roll are skipped
+		assert_ok!(add_voters(&[DAVE, RON]));
+		System::assert_last_event(Event::VotersAdded { count: 1 }.into());
+
+		assert_ok!(TemplateModule::remove_voter(root_user(), RON));
+		System::assert_last_event(Event::VoterRemoved { who: RON }.into());
+
+		set_current_time(TIME_DURING_ELECTION);
+		assert_noop!(give_vote(who(ALICE), JOHN), Error::<Test>::NotRegistered);
+		assert_noop!(give_vote(who(RON), JOHN), Error::<Test>::NotRegistered);
+		assert_ok!(give_vote(who(BOB), JOHN));
+		assert_ok!(give_vote(who(DAVE), JOHN));
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, JOHN).unwrap().vote_count(), 2);
+	})
+}
+
+#[test]
+fn test_open_poll_admits_self_registered_and_roll_voters() {
+	ExtBuilder::default().build().execute_with(|| {
+		configure_election_start_and_end_time();
+		register_voters(&[ALICE]);
+		assert_ok!(add_voters(&[BOB]));
+		register_candidates(&[JOHN]);
+
+		set_current_time(TIME_DURING_ELECTION);
+		assert_ok!(give_vote(who(ALICE), JOHN));
+		assert_ok!(give_vote(who(BOB), JOHN));
+		assert_noop!(give_vote(who(DAVE), JOHN), Error::<Test>::NotRegistered);
+	})
+}
+
+#[test]
+fn test_raise_error_when_voter_roll_changed_without_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			TemplateModule::add_voters(who(ALICE), vec![ALICE].try_into().unwrap()),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(add_voters(&[BOB]));
+		assert_noop!(TemplateModule::remove_voter(who(BOB), BOB), DispatchError::BadOrigin);
+		assert_noop!(
+			TemplateModule::remove_voter(root_user(), ALICE),
+			Error::<Test>::NotOnVoterRoll
+		);
+	})
+}
+
+#[test]
+fn test_candidacy_bond_returned_above_threshold_and_forfeited_below() {
+	ExtBuilder::default().candidacy_bond(10).build().execute_with(|| {
+		set_current_time(1);
+		configure_election_start_and_end_time();
+		register_voters(&[ALICE, BOB, DAVE, RON, JOHN]);
+		register_candidates(&[ALICE, BOB, DAVE]);
+		assert_eq!(Balances::reserved_balance(DAVE), 10);
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, DAVE).unwrap().bond(), 10);
+
+		set_current_time(TIME_DURING_ELECTION);
+		for voter in [ALICE, BOB, RON, JOHN] {
+			assert_ok!(give_vote(who(voter), ALICE));
+		}
+		// A fifth of the votes is just enough to get the bond back
+		assert_ok!(give_vote(who(DAVE), BOB));
+
+		set_current_time(TIME_AFTER_ELECTION);
+		assert_ok!(who_won_elections());
+		System::assert_has_event(
+			Event::BondReturned { election_id: ELECTION_ID, candidate: BOB, amount: 10 }.into(),
+		);
+		System::assert_has_event(
+			Event::BondForfeited { election_id: ELECTION_ID, candidate: DAVE, amount: 10 }.into(),
+		);
+		assert_eq!(Balances::free_balance(ALICE), 100);
+		assert_eq!(Balances::free_balance(BOB), 50);
+		assert_eq!(Balances::reserved_balance(DAVE), 0);
+		assert_eq!(Balances::free_balance(DAVE), 20);
+		assert_eq!(Balances::free_balance(TREASURY), 10);
+
+		// A counted election is closed, so no bond is settled twice
+		assert_noop!(who_won_elections(), Error::<Test>::ElectionClosed);
+		assert_eq!(Balances::free_balance(TREASURY), 10);
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, DAVE).unwrap().bond(), 0);
+	})
+}
+
+#[test]
+fn test_raise_error_when_candidate_cannot_afford_bond() {
+	ExtBuilder::default().candidacy_bond(40).build().execute_with(|| {
+		configure_election_start_and_end_time();
+		assert_noop!(register_candidate(who(JOHN)), Error::<Test>::InsufficientBond);
+		assert_noop!(register_candidate(who(RON)), Error::<Test>::InsufficientBond);
+		assert_ok!(register_candidate(who(ALICE)));
+		assert_eq!(Balances::reserved_balance(ALICE), 40);
+	})
+}
+
+#[test]
+fn test_raise_error_when_candidate_registers_once_voting_opens() {
+	ExtBuilder::default().build().execute_with(|| {
+		configure_election_start_and_end_time();
+
+		set_current_time(ELECTION_START_TIME);
+		assert_noop!(register_candidate(who(BOB)), Error::<Test>::NominationEnded);
+		set_current_time(TIME_AFTER_ELECTION);
+		assert_noop!(register_candidate(who(BOB)), Error::<Test>::NominationEnded);
+	})
+}
+
+#[test]
+fn test_phased_election_gates_nomination_enrollment_and_voting() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(configure_phased_election());
+		register_voters(&[ALICE, DAVE]);
+		assert_noop!(register_candidate(who(BOB)), Error::<Test>::NominationNotStarted);
+
+		set_current_time(NOMINATION.start);
+		assert_ok!(register_candidate(who(BOB)));
+		assert_noop!(enroll(who(ALICE)), Error::<Test>::RegistrationNotStarted);
+
+		set_current_time(REGISTRATION.start);
+		assert_noop!(register_candidate(who(RON)), Error::<Test>::NominationEnded);
+		assert_ok!(enroll(who(ALICE)));
+		System::assert_last_event(
+			Event::VoterEnrolled { election_id: ELECTION_ID, who: ALICE }.into(),
+		);
+		assert_noop!(enroll(who(ALICE)), Error::<Test>::AlreadyRegistered);
+		assert_noop!(enroll(who(JOHN)), Error::<Test>::NotRegistered);
+
+		set_current_time(ELECTION_START_TIME);
+		assert_noop!(enroll(who(DAVE)), Error::<Test>::RegistrationEnded);
+		// DAVE registered as a voter but never enrolled in this election
+		assert_noop!(give_vote(who(DAVE), BOB), Error::<Test>::NotRegistered);
+		assert_ok!(give_vote(who(ALICE), BOB));
+	})
+}
+
+#[test]
+fn test_raise_error_when_phases_are_misplaced() {
+	ExtBuilder::default().build().execute_with(|| {
+		configure_election_start_and_end_time();
+		register_voters(&[ALICE]);
+		assert_noop!(enroll(who(ALICE)), Error::<Test>::RegistrationNotScheduled);
+
+		let overlapping = PhaseWindow { start: 1, end: ELECTION_START_TIME };
+		let backwards = PhaseWindow { start: 3, end: 2 };
+		for window in [overlapping, backwards] {
+			assert_noop!(
+				TemplateModule::create_election(
+					root_user(),
+					ELECTION_START_TIME,
+					ELECTION_END_TIME,
+					None,
+					Default::default(),
+					ElectionPhases { nomination: None, registration: Some(window) },
+				),
+				Error::<Test>::ElectionTimeIllogical
+			);
+		}
+	})
+}
+
+#[test]
+fn test_election_is_finalized_in_the_block_after_it_closes() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_for_one_voter_one_candidate_and_election_time();
+		assert_eq!(
+			TemplateModule::elections_to_finalize(ELECTION_END_TIME + 1).into_inner(),
+			vec![ELECTION_ID]
+		);
+
+		set_current_time(TIME_DURING_ELECTION);
+		assert_ok!(give_vote(who(ALICE), BOB));
+
+		set_current_time(ELECTION_END_TIME);
+		TemplateModule::on_initialize(ELECTION_END_TIME);
+		assert_eq!(TemplateModule::max_votes_candidate(ELECTION_ID), None);
+
+		// The hook is charged for counting the election's one candidate
+		set_current_time(ELECTION_END_TIME + 1);
+		let weight = TemplateModule::on_initialize(ELECTION_END_TIME + 1);
+		assert!(weight.all_gte(<Test as Config>::WeightInfo::winner(1)));
+		System::assert_last_event(Event::ElectionFinalized { election_id: ELECTION_ID }.into());
+		assert_eq!(
+			TemplateModule::max_votes_candidate(ELECTION_ID).unwrap().into_inner(),
+			vec![BOB]
+		);
+		assert!(TemplateModule::elections_to_finalize(ELECTION_END_TIME + 1).is_empty());
+	})
+}
+
+#[test]
+fn test_runoff_election_is_charged_for_a_count_per_round() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(configure_election_with(VotingMethod::InstantRunoff));
+		register_voters(&[ALICE, BOB]);
+		register_candidates(&[DAVE, RON, JOHN]);
+
+		set_current_time(TIME_DURING_ELECTION);
+		assert_ok!(give_ranked_vote(who(ALICE), &[DAVE, RON]));
+		assert_ok!(give_ranked_vote(who(BOB), &[RON, JOHN]));
+
+		set_current_time(ELECTION_END_TIME + 1);
+		let weight = TemplateModule::on_initialize(ELECTION_END_TIME + 1);
+		assert!(weight.all_gte(<Test as Config>::WeightInfo::winner_stv(3, 2)));
+	})
+}
+
+#[test]
+fn test_raise_error_when_election_would_close_before_next_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_current_time(ELECTION_END_TIME);
+		assert_ok!(configure_election_start_and_end_time());
+
+		// Closing in the last block would schedule it for this one, whose hook has already run
+		set_current_time(ELECTION_END_TIME + 1);
+		assert_noop!(configure_election_start_and_end_time(), Error::<Test>::ElectionEndPassed);
+		assert_ok!(configure_commit_reveal_election());
+	})
+}
+
+#[test]
+fn test_finalization_waits_for_reveal_and_skips_elections_counted_by_hand() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_current_time(1);
+		configure_commit_reveal_election();
+		configure_election_start_and_end_time();
+		assert_eq!(
+			TemplateModule::elections_to_finalize(REVEAL_END_TIME + 1).into_inner(),
+			vec![ELECTION_ID]
+		);
+
+		// The open-ballot election was counted by hand as soon as it closed
+		set_current_time(ELECTION_END_TIME + 1);
+		assert_ok!(TemplateModule::winner(who(ALICE), OTHER_ELECTION_ID, 0));
+		TemplateModule::on_initialize(ELECTION_END_TIME + 1);
+		System::assert_last_event(Event::WinnerVecStored { election_id: OTHER_ELECTION_ID }.into());
+
+		set_current_time(REVEAL_END_TIME + 1);
+		TemplateModule::on_initialize(REVEAL_END_TIME + 1);
+		System::assert_last_event(Event::ElectionFinalized { election_id: ELECTION_ID }.into());
+	})
+}
+
+#[test]
+fn test_finalization_leaves_elections_over_the_weight_budget_to_winner() {
+	let budget = <Test as Config>::WeightInfo::winner(1);
+	ExtBuilder::default().max_finalization_weight(budget).build().execute_with(|| {
+		set_current_time(1);
+		configure_election_start_and_end_time();
+		configure_election_start_and_end_time();
+		register_candidates(&[DAVE]);
+		assert_ok!(register_candidate_in(who(RON), OTHER_ELECTION_ID));
+
+		// Counting the first election uses up the budget, so the second is deferred
+		set_current_time(ELECTION_END_TIME + 1);
+		let weight = TemplateModule::on_initialize(ELECTION_END_TIME + 1);
+		assert_eq!(weight, budget);
+		System::assert_has_event(Event::ElectionFinalized { election_id: ELECTION_ID }.into());
+		System::assert_last_event(
+			Event::ElectionFinalizationDeferred { election_id: OTHER_ELECTION_ID }.into(),
+		);
+		assert_eq!(TemplateModule::election_status(OTHER_ELECTION_ID), None);
+
+		assert_ok!(TemplateModule::winner(who(ALICE), OTHER_ELECTION_ID, 0));
+		System::assert_last_event(Event::WinnerVecStored { election_id: OTHER_ELECTION_ID }.into());
+	})
+}
+
+#[test]
+fn test_raise_error_when_too_many_elections_close_in_one_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(configure_election_start_and_end_time());
+		assert_ok!(configure_election_start_and_end_time());
+		assert_noop!(
+			configure_election_start_and_end_time(),
+			Error::<Test>::TooManyElectionsEnding
+		);
+	})
+}
+
+#[test]
+fn test_tie_goes_to_the_earliest_registered_candidate() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_tied_election(TieBreak::EarliestRegistration);
+
+		assert_ok!(who_won_elections());
+		assert_eq!(
+			TemplateModule::max_votes_candidate(ELECTION_ID).unwrap().into_inner(),
+			vec![RON]
+		);
+		let outcome = TemplateModule::tie_breaks(ELECTION_ID).unwrap();
+		assert_eq!(outcome.policy, TieBreak::EarliestRegistration);
+		assert_eq!(outcome.winner, Some(RON));
+		assert_eq!(outcome.runoff, None);
+		System::assert_has_event(
+			Event::TieBroken {
+				election_id: ELECTION_ID,
+				policy: TieBreak::EarliestRegistration,
+				winner: Some(RON),
+				runoff: None,
+			}
+			.into(),
+		);
+	})
+}
+
+#[test]
+fn test_random_tie_break_keeps_its_draw_when_counted_again() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_tied_election(TieBreak::Random);
+
+		RandomDraw::set(&3);
+		assert_ok!(who_won_elections());
+		assert_eq!(
+			TemplateModule::max_votes_candidate(ELECTION_ID).unwrap().into_inner(),
+			vec![DAVE]
+		);
+		assert_eq!(TemplateModule::tie_breaks(ELECTION_ID).unwrap().policy, TieBreak::Random);
+
+		RandomDraw::set(&0);
+		assert_noop!(who_won_elections(), Error::<Test>::ElectionClosed);
+		assert_eq!(
+			TemplateModule::max_votes_candidate(ELECTION_ID).unwrap().into_inner(),
+			vec![DAVE]
+		);
+	})
+}
+
+#[test]
+fn test_tie_is_kept_without_a_tie_break() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_tied_election(TieBreak::KeepAll);
+
+		assert_ok!(who_won_elections());
+		let mut winners = TemplateModule::max_votes_candidate(ELECTION_ID).unwrap().into_inner();
+		winners.sort();
+		assert_eq!(winners, vec![DAVE, RON]);
+		assert_eq!(TemplateModule::tie_breaks(ELECTION_ID), None);
+	})
+}
+
+#[test]
+fn test_runoff_tie_break_schedules_a_runoff_among_the_tied_candidates() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_tied_election(TieBreak::Runoff);
+		let runoff = OTHER_ELECTION_ID;
+
+		assert_ok!(who_won_elections());
+		let outcome = TemplateModule::tie_breaks(ELECTION_ID).unwrap();
+		assert_eq!(outcome.winner, None);
+		assert_eq!(outcome.runoff, Some(runoff));
+		assert_eq!(TemplateModule::max_votes_candidate(ELECTION_ID), Some(outcome.tied));
+		System::assert_has_event(Event::ElectionCreated { election_id: runoff }.into());
+
+		// The runoff opens at once, for as long as the tied election's voting ran
+		let length = ELECTION_END_TIME - ELECTION_START_TIME;
+		let runoff_end = TIME_AFTER_ELECTION + length;
+		assert_eq!(
+			TemplateModule::elections_to_finalize(runoff_end + 1).into_inner(),
+			vec![runoff]
+		);
+		assert_eq!(TemplateModule::candidate_account(runoff, BOB), None);
+		assert_eq!(TemplateModule::candidate_account(runoff, RON).unwrap().vote_count(), 0);
+		assert_noop!(register_candidate_in(who(BOB), runoff), Error::<Test>::NominationEnded);
+
+		// The tied election is closed, so no second runoff can be scheduled
+		assert_noop!(who_won_elections(), Error::<Test>::ElectionClosed);
+		assert_eq!(TemplateModule::next_election_id(), runoff + 1);
+
+		assert_ok!(TemplateModule::give_vote(who(ALICE), runoff, RON));
+		set_current_time(runoff_end + 1);
+		TemplateModule::on_initialize(runoff_end + 1);
+		assert_eq!(TemplateModule::max_votes_candidate(runoff).unwrap().into_inner(), vec![RON]);
+	})
+}
+
+#[test]
+fn test_raise_error_when_breaking_ties_in_stv_election() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			TemplateModule::create_election(
+				root_user(),
+				ELECTION_START_TIME,
+				ELECTION_END_TIME,
+				None,
+				ElectionSettings {
+					method: VotingMethod::Stv,
+					seats: 2,
+					tie_break: TieBreak::EarliestRegistration,
+					..Default::default()
+				},
+				Default::default(),
+			),
+			Error::<Test>::TieBreakNotSupported
+		);
+	})
+}
+
+#[test]
+fn test_majority_winner_needs_no_runoff() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_majority_election(&[(ALICE, BOB), (JOHN, BOB), (DAVE, BOB), (RON, RON)]);
+
+		assert_ok!(who_won_elections());
+		assert_eq!(
+			TemplateModule::max_votes_candidate(ELECTION_ID).unwrap().into_inner(),
+			vec![BOB]
+		);
+		assert_eq!(TemplateModule::runoff_election(ELECTION_ID), None);
+	})
+}
+
+#[test]
+fn test_runoff_between_top_two_when_no_majority_is_reached() {
+	ExtBuilder::default().build().execute_with(|| {
+		// BOB leads with exactly half the ballots. RON and DAVE are level, and RON registered first
+		setup_majority_election(&[(ALICE, BOB), (JOHN, BOB), (DAVE, DAVE), (RON, RON)]);
+		let runoff = OTHER_ELECTION_ID;
+
+		assert_ok!(who_won_elections());
+		assert!(TemplateModule::max_votes_candidate(ELECTION_ID).unwrap().is_empty());
+		assert_eq!(TemplateModule::runoff_election(ELECTION_ID), Some(runoff));
+		assert_eq!(TemplateModule::runoff_of(runoff), Some(ELECTION_ID));
+		System::assert_has_event(
+			Event::MajorityNotReached { election_id: ELECTION_ID, runoff }.into(),
+		);
+
+		assert!(TemplateModule::candidate_account(runoff, BOB).is_some());
+		assert!(TemplateModule::candidate_account(runoff, RON).is_some());
+		assert_eq!(TemplateModule::candidate_account(runoff, DAVE), None);
+		let runoff_end = TIME_AFTER_ELECTION + u64::from(RUNOFF_DURATION);
+		assert_eq!(
+			TemplateModule::elections_to_finalize(runoff_end + 1).into_inner(),
+			vec![runoff]
+		);
+
+		// The election is closed, so no second runoff can be scheduled
+		assert_noop!(who_won_elections(), Error::<Test>::ElectionClosed);
+		assert_eq!(TemplateModule::next_election_id(), runoff + 1);
+
+		assert_ok!(TemplateModule::give_vote(who(DAVE), runoff, RON));
+		set_current_time(runoff_end + 1);
+		TemplateModule::on_initialize(runoff_end + 1);
+		assert_eq!(TemplateModule::max_votes_candidate(runoff).unwrap().into_inner(), vec![RON]);
+	})
+}
+
+#[test]
+fn test_raise_error_when_requiring_majority_in_non_plurality_election() {
+	ExtBuilder::default().build().execute_with(|| {
+		let majority = MajorityRule { threshold: Perbill::from_percent(50), runoff_duration: 10 };
+		assert_noop!(
+			TemplateModule::create_election(
+				root_user(),
+				ELECTION_START_TIME,
+				ELECTION_END_TIME,
+				None,
+				ElectionSettings {
+					method: VotingMethod::Approval,
+					majority: Some(majority),
+					..Default::default()
+				},
+				Default::default(),
+			),
+			Error::<Test>::MajorityNotSupported
+		);
+		assert_noop!(
+			TemplateModule::create_election(
+				root_user(),
+				ELECTION_START_TIME,
+				ELECTION_END_TIME,
+				None,
+				ElectionSettings {
+					majority: Some(MajorityRule { runoff_duration: 0, ..majority }),
+					..Default::default()
+				},
+				Default::default(),
+			),
+			Error::<Test>::ElectionTimeIllogical
+		);
+	})
+}
+
+#[test]
+fn test_election_below_quorum_is_invalid_and_returns_bonds() {
+	ExtBuilder::default().candidacy_bond(10).build().execute_with(|| {
+		set_current_time(1);
+		assert_ok!(configure_election_with_settings(ElectionSettings {
+			quorum: Some(Perbill::from_percent(50)),
+			..Default::default()
+		}));
+		assert_ok!(register_candidate(who(DAVE)));
+		register_voters(&[ALICE, BOB, DAVE, RON]);
+
+		set_current_time(TIME_DURING_ELECTION);
+		assert_ok!(give_vote(who(ALICE), DAVE));
+
+		set_current_time(TIME_AFTER_ELECTION);
+		assert_ok!(who_won_elections());
+		let turnout = Turnout { voted: 1, electorate: 4 };
+		System::assert_last_event(
+			Event::QuorumNotReached { election_id: ELECTION_ID, turnout }.into(),
+		);
+		assert_eq!(TemplateModule::election_status(ELECTION_ID), Some(ElectionStatus::Invalid));
+		assert_eq!(TemplateModule::max_votes_candidate(ELECTION_ID), None);
+		assert_eq!(TemplateModule::election_turnout(ELECTION_ID), Some(turnout));
+		assert_eq!(Balances::reserved_balance(DAVE), 0);
+		assert_eq!(Balances::free_balance(DAVE), 30);
+
+		// Automatic finalization leaves the invalid election alone
+		TemplateModule::on_initialize(ELECTION_END_TIME + 1);
+		System::assert_last_event(
+			Event::QuorumNotReached { election_id: ELECTION_ID, turnout }.into(),
+		);
+	})
+}
+
+#[test]
+fn test_election_meeting_quorum_stores_winner_and_turnout() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_current_time(1);
+		assert_ok!(configure_election_with_settings(ElectionSettings {
+			quorum: Some(Perbill::from_percent(50)),
+			..Default::default()
+		}));
+		assert_ok!(register_candidate(who(DAVE)));
+		register_voters(&[ALICE, BOB, DAVE, RON]);
+
+		set_current_time(TIME_DURING_ELECTION);
+		assert_ok!(give_vote(who(ALICE), DAVE));
+		assert_ok!(give_vote(who(BOB), DAVE));
+
+		set_current_time(TIME_AFTER_ELECTION);
+		assert_ok!(who_won_elections());
+		assert_eq!(TemplateModule::election_status(ELECTION_ID), Some(ElectionStatus::Finalized));
+		assert_eq!(
+			TemplateModule::max_votes_candidate(ELECTION_ID).unwrap().into_inner(),
+			vec![DAVE]
+		);
+		assert_eq!(
+			TemplateModule::election_turnout(ELECTION_ID),
+			Some(Turnout { voted: 2, electorate: 4 })
+		);
+	})
+}
+
+#[test]
+fn test_electorate_counts_follow_registration_and_voter_roll() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(add_voters(&[ALICE, BOB]));
+		register_voters(&[ALICE, DAVE]);
+		assert_eq!(TemplateModule::voter_roll_count(), 2);
+		assert_eq!(TemplateModule::open_electorate_count(), 3);
+
+		assert_ok!(TemplateModule::remove_voter(root_user(), ALICE));
+		assert_ok!(TemplateModule::remove_voter(root_user(), BOB));
+		assert_eq!(TemplateModule::voter_roll_count(), 0);
+		assert_eq!(TemplateModule::open_electorate_count(), 2);
+	})
+}
+
+#[test]
+fn test_voted_count_follows_ballots_and_revocations() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(configure_election_start_and_end_time());
+		register_candidates(&[DAVE, RON]);
+		register_voters(&[ALICE, BOB, JOHN]);
+
+		set_current_time(TIME_DURING_ELECTION);
+		assert_ok!(give_vote(who(ALICE), DAVE));
+		assert_ok!(give_vote(who(BOB), RON));
+		assert_ok!(delegate(who(JOHN), ALICE));
+		assert_eq!(TemplateModule::voted_count(ELECTION_ID), 2);
+
+		assert_ok!(revoke_vote(who(BOB)));
+		assert_eq!(TemplateModule::voted_count(ELECTION_ID), 1);
+		assert_ok!(give_vote(who(BOB), DAVE));
+		assert_eq!(TemplateModule::voted_count(ELECTION_ID), 2);
+	})
+}
+
+#[test]
+fn test_election_status_follows_its_lifecycle() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(TemplateModule::election_status(ELECTION_ID), None);
+		set_current_time(1);
+		assert_ok!(configure_phased_election());
+		assert_eq!(TemplateModule::election_status(ELECTION_ID), Some(ElectionStatus::Scheduled));
+
+		set_current_time(REGISTRATION.start);
+		assert_eq!(
+			TemplateModule::election_status(ELECTION_ID),
+			Some(ElectionStatus::Registration)
+		);
+		set_current_time(TIME_DURING_ELECTION);
+		assert_eq!(TemplateModule::election_status(ELECTION_ID), Some(ElectionStatus::Voting));
+		set_current_time(ELECTION_END_TIME + 1);
+		assert_eq!(TemplateModule::election_status(ELECTION_ID), Some(ElectionStatus::Tallying));
+
+		TemplateModule::on_initialize(ELECTION_END_TIME + 1);
+		assert_eq!(TemplateModule::election_status(ELECTION_ID), Some(ElectionStatus::Finalized));
+	})
+}
+
+#[test]
+fn test_cancelled_election_refuses_ballots_and_releases_bonds_and_locks() {
+	ExtBuilder::default().candidacy_bond(10).build().execute_with(|| {
+		set_current_time(1);
+		assert_ok!(configure_election_with_settings(ElectionSettings {
+			weighting: VoteWeighting::FreeBalance,
+			conviction: true,
+			..Default::default()
+		}));
+		assert_ok!(register_candidate(who(DAVE)));
+		register_voters(&[ALICE, BOB]);
+		set_current_time(TIME_DURING_ELECTION);
+		assert_ok!(give_conviction_vote(who(ALICE), DAVE, Conviction::Locked6x));
+
+		assert_noop!(
+			TemplateModule::cancel_election(who(ALICE), ELECTION_ID),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(TemplateModule::cancel_election(root_user(), ELECTION_ID));
+		System::assert_last_event(Event::ElectionCancelled { election_id: ELECTION_ID }.into());
+		assert_eq!(TemplateModule::election_status(ELECTION_ID), Some(ElectionStatus::Cancelled));
+		assert!(TemplateModule::elections_to_finalize(ELECTION_END_TIME + 1).is_empty());
+		assert_eq!(Balances::reserved_balance(DAVE), 0);
+
+		assert_noop!(give_vote(who(BOB), DAVE), Error::<Test>::ElectionCancelled);
+		assert_ok!(TemplateModule::unlock(who(ALICE), ELECTION_ID));
+
+		set_current_time(TIME_AFTER_ELECTION);
+		assert_noop!(who_won_elections(), Error::<Test>::ElectionCancelled);
+		assert_noop!(
+			TemplateModule::cancel_election(root_user(), ELECTION_ID),
+			Error::<Test>::ElectionClosed
+		);
+	})
+}
+
+#[test]
+fn test_extend_voting_moves_the_end_and_the_reveal_window() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_current_time(1);
+		assert_ok!(configure_commit_reveal_election());
+		let new_end = ELECTION_END_TIME + 5;
+		let new_reveal_end = REVEAL_END_TIME + 5;
+
+		set_current_time(TIME_DURING_ELECTION);
+		assert_noop!(
+			TemplateModule::extend_voting(root_user(), ELECTION_ID, ELECTION_END_TIME),
+			Error::<Test>::ElectionTimeIllogical
+		);
+		assert_ok!(TemplateModule::extend_voting(root_user(), ELECTION_ID, new_end));
+		System::assert_last_event(
+			Event::VotingExtended { election_id: ELECTION_ID, end: new_end }.into(),
+		);
+		assert_eq!(
+			TemplateModule::get_election(ELECTION_ID).unwrap(),
+			ElectionInfo::set(
+				ELECTION_START_TIME,
+				new_end,
+				Some(new_reveal_end),
+				Default::default()
+			)
+		);
+		assert!(TemplateModule::elections_to_finalize(REVEAL_END_TIME + 1).is_empty());
+		assert_eq!(
+			TemplateModule::elections_to_finalize(new_reveal_end + 1).into_inner(),
+			vec![ELECTION_ID]
+		);
+
+		set_current_time(new_end + 1);
+		assert_noop!(
+			TemplateModule::extend_voting(root_user(), ELECTION_ID, new_end + 10),
+			Error::<Test>::ElectionEnded
+		);
+	})
+}
+
+#[test]
+fn test_extend_voting_moves_the_locks_its_votes_hold() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(configure_election_with_settings(ElectionSettings {
+			weighting: VoteWeighting::FreeBalance,
+			conviction: true,
+			..Default::default()
+		}));
+		register_voters(&[ALICE, BOB]);
+		register_candidates(&[DAVE]);
+		let new_end = ELECTION_END_TIME + 20;
+
+		set_current_time(TIME_DURING_ELECTION);
+		assert_ok!(give_conviction_vote(who(ALICE), DAVE, Conviction::Locked1x));
+		assert_ok!(give_vote(who(BOB), DAVE));
+		assert_ok!(TemplateModule::extend_voting(root_user(), ELECTION_ID, new_end));
+
+		// Both locks would have lapsed by now had they kept the original end
+		set_current_time(ELECTION_END_TIME + 10);
+		assert_noop!(
+			TemplateModule::unlock(who(ALICE), ELECTION_ID),
+			Error::<Test>::LockNotExpired
+		);
+		assert_noop!(TemplateModule::unlock(who(BOB), ELECTION_ID), Error::<Test>::LockNotExpired);
+
+		set_current_time(new_end);
+		assert_ok!(TemplateModule::unlock(who(BOB), ELECTION_ID));
+		assert_noop!(
+			TemplateModule::unlock(who(ALICE), ELECTION_ID),
+			Error::<Test>::LockNotExpired
+		);
+
+		set_current_time(new_end + 10);
+		assert_ok!(TemplateModule::unlock(who(ALICE), ELECTION_ID));
+	})
+}
+
+#[test]
+fn test_postpone_moves_an_election_that_has_not_opened() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_current_time(1);
+		assert_ok!(configure_election_start_and_end_time());
+		let (new_start, new_end) = (ELECTION_START_TIME + 10, ELECTION_END_TIME + 10);
+
+		assert_noop!(
+			TemplateModule::postpone(root_user(), ELECTION_ID, ELECTION_START_TIME - 1, new_end),
+			Error::<Test>::ElectionTimeIllogical
+		);
+		assert_ok!(TemplateModule::postpone(root_user(), ELECTION_ID, new_start, new_end));
+		System::assert_last_event(
+			Event::ElectionPostponed { election_id: ELECTION_ID, start: new_start, end: new_end }
+				.into(),
+		);
+		assert_eq!(
+			TemplateModule::get_election(ELECTION_ID).unwrap(),
+			ElectionInfo::set(new_start, new_end, None, Default::default())
+		);
+		assert!(TemplateModule::elections_to_finalize(ELECTION_END_TIME + 1).is_empty());
+		assert_eq!(
+			TemplateModule::elections_to_finalize(new_end + 1).into_inner(),
+			vec![ELECTION_ID]
+		);
+
+		set_current_time(new_start);
+		assert_noop!(
+			TemplateModule::postpone(root_user(), ELECTION_ID, new_start + 5, new_end + 5),
+			Error::<Test>::ElectionStarted
+		);
+	})
+}
+
+#[test]
+fn test_result_is_archived_when_election_is_counted() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_current_time(1);
+		assert_ok!(configure_election_start_and_end_time());
+		register_voters(&[ALICE, BOB, JOHN]);
+		register_candidates(&[RON, DAVE, BOB]);
+
+		set_current_time(TIME_DURING_ELECTION);
+		assert_ok!(give_vote(who(ALICE), DAVE));
+		assert_ok!(give_vote(who(BOB), DAVE));
+		assert_ok!(give_vote(who(JOHN), RON));
+		assert_eq!(TemplateModule::election_result(ELECTION_ID), None);
+
+		set_current_time(TIME_AFTER_ELECTION);
+		assert_ok!(who_won_elections());
+		let result = TemplateModule::election_result(ELECTION_ID).unwrap();
+		assert_eq!(result.status, ElectionStatus::Finalized);
+		assert_eq!(result.method, VotingMethod::Plurality);
+		assert_eq!(result.counts.into_inner(), vec![(RON, 1), (DAVE, 2), (BOB, 0)]);
+		assert_eq!(result.winners.into_inner(), vec![DAVE]);
+		assert_eq!(result.turnout, Turnout { voted: 3, electorate: 3 });
+		assert_eq!(result.finalized_at, TIME_AFTER_ELECTION);
+	})
+}
+
+#[test]
+fn test_archived_result_outlives_later_elections() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_tied_election(TieBreak::EarliestRegistration);
+		assert_ok!(who_won_elections());
+		let result = TemplateModule::election_result(ELECTION_ID).unwrap();
+		assert_eq!(result.winners.clone().into_inner(), vec![RON]);
+
+		assert_noop!(who_won_elections(), Error::<Test>::ElectionClosed);
+		assert_ok!(TemplateModule::create_election(
+			root_user(),
+			TIME_AFTER_ELECTION + 1,
+			TIME_AFTER_ELECTION + 10,
+			None,
+			Default::default(),
+			Default::default(),
+		));
+		assert_ok!(register_candidate_in(who(DAVE), OTHER_ELECTION_ID));
+		set_current_time(TIME_AFTER_ELECTION + 11);
+		TemplateModule::on_initialize(TIME_AFTER_ELECTION + 11);
+		let later = TemplateModule::election_result(OTHER_ELECTION_ID).unwrap();
+		assert_eq!(later.winners.into_inner(), vec![DAVE]);
+		assert_eq!(TemplateModule::election_result(ELECTION_ID), Some(result));
+	})
+}
+
+#[test]
+fn test_purge_removes_a_closed_elections_records_in_batches() {
+	let mut ext = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		setup_tied_election(TieBreak::EarliestRegistration);
+		assert_noop!(
+			TemplateModule::purge_election(who(ALICE), ELECTION_ID, 10),
+			Error::<Test>::ElectionNotClosed
+		);
+		assert_ok!(who_won_elections());
+		assert!(TemplateModule::pending_purge(ELECTION_ID).is_some());
+		assert!(TemplateModule::tie_breaks(ELECTION_ID).is_some());
+	});
+	// Batches count the records already in the database
+	ext.commit_all().unwrap();
+
+	ext.execute_with(|| {
+		let candidates_left = || {
+			[BOB, RON, DAVE]
+				.iter()
+				.filter(|c| TemplateModule::candidate_account(ELECTION_ID, c).is_some())
+				.count()
+		};
+		assert_ok!(TemplateModule::purge_election(who(ALICE), ELECTION_ID, 3));
+		assert_eq!(TemplateModule::voter_account(ELECTION_ID, ALICE), None);
+		assert_eq!(TemplateModule::voter_account(ELECTION_ID, JOHN), None);
+		assert_eq!(candidates_left(), 2);
+		assert!(TemplateModule::pending_purge(ELECTION_ID).is_some());
+
+		assert_ok!(TemplateModule::purge_election(who(ALICE), ELECTION_ID, 1));
+		assert_eq!(candidates_left(), 1);
+		assert_eq!(TemplateModule::voted_count(ELECTION_ID), 2);
+
+		assert_ok!(TemplateModule::purge_election(who(ALICE), ELECTION_ID, 10));
+		assert_eq!(candidates_left(), 0);
+		System::assert_last_event(Event::ElectionPurged { election_id: ELECTION_ID }.into());
+		assert_eq!(TemplateModule::pending_purge(ELECTION_ID), None);
+		assert_eq!(TemplateModule::candidate_count(ELECTION_ID), 0);
+		assert_eq!(TemplateModule::max_votes(ELECTION_ID), 0);
+		assert_eq!(TemplateModule::voted_count(ELECTION_ID), 0);
+		assert_eq!(TemplateModule::max_votes_candidate(ELECTION_ID), None);
+		assert_eq!(TemplateModule::tie_breaks(ELECTION_ID), None);
+		assert_eq!(
+			TemplateModule::election_result(ELECTION_ID).unwrap().winners.into_inner(),
+			vec![RON]
+		);
+		assert_noop!(
+			TemplateModule::purge_election(who(ALICE), ELECTION_ID, 10),
+			Error::<Test>::ElectionAlreadyPurged
+		);
+	})
+}
+
+#[test]
+fn test_purge_removes_a_schulze_elections_pairwise_preferences() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(configure_election_with(VotingMethod::Schulze));
+		cast_borda_split_ballots();
+		set_current_time(TIME_AFTER_ELECTION);
+		assert_ok!(who_won_elections());
+		assert_eq!(TemplateModule::pairwise_preference(ELECTION_ID, (DAVE, RON)), 3);
+
+		assert_ok!(TemplateModule::purge_election(who(ALICE), ELECTION_ID, 100));
+		System::assert_last_event(Event::ElectionPurged { election_id: ELECTION_ID }.into());
+		assert_eq!(TemplateModule::pairwise_preference(ELECTION_ID, (DAVE, RON)), 0);
+		assert_eq!(TemplateModule::pairwise_preference(ELECTION_ID, (RON, JOHN)), 0);
+		assert_eq!(TemplateModule::condorcet_winner(ELECTION_ID), None);
+	})
+}
+
+#[test]
+fn test_idle_blocks_purge_closed_elections() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_tied_election(TieBreak::KeepAll);
+		TemplateModule::on_idle(TIME_AFTER_ELECTION, Weight::MAX);
+		assert!(TemplateModule::candidate_account(ELECTION_ID, BOB).is_some());
+
+		assert_ok!(who_won_elections());
+		// Too little weight for a single record leaves the election as it is
+		assert_eq!(TemplateModule::on_idle(TIME_AFTER_ELECTION, Weight::zero()), Weight::zero());
+		assert!(TemplateModule::pending_purge(ELECTION_ID).is_some());
+
+		TemplateModule::on_idle(TIME_AFTER_ELECTION, Weight::MAX);
+		System::assert_last_event(Event::ElectionPurged { election_id: ELECTION_ID }.into());
+		assert_eq!(TemplateModule::candidate_account(ELECTION_ID, BOB), None);
+		assert_eq!(TemplateModule::voter_account(ELECTION_ID, ALICE), None);
+		assert_eq!(TemplateModule::pending_purge(ELECTION_ID), None);
 	})
 }
 
@@ -207,9 +2276,9 @@ impl Voted for VoterInfo<Test> {
 	}
 }
 
-impl Voted for CandidateInfo {
+impl Voted for CandidateInfo<Test> {
 	fn voted() -> Self {
-		CandidateInfo::set(1)
+		CandidateInfo::set(candidate_name(), None, H256::zero(), 1)
 	}
 }
 
@@ -217,6 +2286,6 @@ impl Voted for ElectionInfo<Test> {
 	fn voted() -> Self {
 		let start = ELECTION_START_TIME;
 		let end = ELECTION_END_TIME;
-		ElectionInfo::set(start, end)
+		ElectionInfo::set(start, end, None, Default::default())
 	}
 }
@@ -38,76 +38,619 @@ use frame_support::pallet_prelude::PhantomData;
 
 pub struct TemplateWeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> crate::WeightInfo for TemplateWeightInfo<T> {
-	/// Storage: TemplateModule AccountToVoterInfo (r:1 w:1)
-	/// Proof: TemplateModule AccountToVoterInfo (max_values: None, max_size: Some(82), added: 2557, mode: MaxEncodedLen)
+	/// Storage: TemplateModule RegisteredVoters (r:1 w:1)
+	/// Proof: TemplateModule RegisteredVoters (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VoterRoll (r:1 w:0)
+	/// Proof: TemplateModule VoterRoll (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule OpenElectorateCount (r:1 w:1)
+	/// Proof: TemplateModule OpenElectorateCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	fn add_voter() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `6`
-		//  Estimated: `3547`
-		// Minimum execution time: 10_151_000 picoseconds.
-		Weight::from_parts(10_872_000, 0)
-			.saturating_add(Weight::from_parts(0, 3547))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+		//  Estimated: `7539`
+		// Minimum execution time: 14_203_000 picoseconds.
+		Weight::from_parts(14_917_000, 0)
+			.saturating_add(Weight::from_parts(0, 7539))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	/// Storage: TemplateModule ElectionConfig (r:1 w:0)
+	/// Proof: TemplateModule ElectionConfig (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ClosedElections (r:1 w:0)
+	/// Proof: TemplateModule ClosedElections (max_values: None, max_size: Some(17), added: 2492, mode: MaxEncodedLen)
 	/// Storage: TemplateModule AccountToCandidateInfo (r:1 w:1)
-	/// Proof: TemplateModule AccountToCandidateInfo (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Proof: TemplateModule AccountToCandidateInfo (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: TemplateModule CandidateCount (r:1 w:1)
+	/// Proof: TemplateModule CandidateCount (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	fn register_candidate() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `6`
-		//  Estimated: `3517`
-		// Minimum execution time: 10_158_000 picoseconds.
-		Weight::from_parts(10_684_000, 0)
-			.saturating_add(Weight::from_parts(0, 3517))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+		//  Measured:  `151`
+		//  Estimated: `12923`
+		// Minimum execution time: 31_482_000 picoseconds.
+		Weight::from_parts(32_617_000, 0)
+			.saturating_add(Weight::from_parts(0, 12923))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
-	/// Storage: TemplateModule ElectionConfig (r:1 w:1)
-	/// Proof: TemplateModule ElectionConfig (max_values: Some(1), max_size: Some(10), added: 505, mode: MaxEncodedLen)
-	fn config_election() -> Weight {
+	/// Storage: TemplateModule ElectionConfig (r:1 w:0)
+	/// Proof: TemplateModule ElectionConfig (max_values: None, max_size: Some(22), added: 2497, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AccountToCandidateInfo (r:1 w:1)
+	/// Proof: TemplateModule AccountToCandidateInfo (max_values: None, max_size: Some(293), added: 2768, mode: MaxEncodedLen)
+	fn update_candidate_profile() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `6`
-		//  Estimated: `1495`
-		// Minimum execution time: 9_196_000 picoseconds.
-		Weight::from_parts(12_809_000, 0)
-			.saturating_add(Weight::from_parts(0, 1495))
-			.saturating_add(T::DbWeight::get().reads(1))
+		//  Measured:  `340`
+		//  Estimated: `7245`
+		// Minimum execution time: 14_870_000 picoseconds.
+		Weight::from_parts(15_433_000, 0)
+			.saturating_add(Weight::from_parts(0, 7245))
+			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: TemplateModule NextElectionId (r:1 w:1)
+	/// Proof: TemplateModule NextElectionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ElectionsToFinalize (r:1 w:1)
+	/// Proof: TemplateModule ElectionsToFinalize (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ElectionConfig (r:0 w:1)
+	/// Proof: TemplateModule ElectionConfig (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
+	fn create_election() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `4067`
+		// Minimum execution time: 15_873_000 picoseconds.
+		Weight::from_parts(16_905_000, 0)
+			.saturating_add(Weight::from_parts(0, 4067))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
 	/// Storage: TemplateModule ElectionConfig (r:1 w:0)
-	/// Proof: TemplateModule ElectionConfig (max_values: Some(1), max_size: Some(10), added: 505, mode: MaxEncodedLen)
-	/// Storage: TemplateModule AccountToVoterInfo (r:1 w:1)
-	/// Proof: TemplateModule AccountToVoterInfo (max_values: None, max_size: Some(82), added: 2557, mode: MaxEncodedLen)
-	/// Storage: TemplateModule AccountToCandidateInfo (r:1 w:1)
-	/// Proof: TemplateModule AccountToCandidateInfo (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Proof: TemplateModule ElectionConfig (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
+	/// Storage: TemplateModule RegisteredVoters (r:1 w:0)
+	/// Proof: TemplateModule RegisteredVoters (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AccountToVoterInfo (r:10 w:9)
+	/// Proof: TemplateModule AccountToVoterInfo (max_values: None, max_size: Some(143), added: 2618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AccountToCandidateInfo (r:102 w:2)
+	/// Proof: TemplateModule AccountToCandidateInfo (max_values: None, max_size: Some(305), added: 2780, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ConvictionLocks (r:1 w:1)
+	/// Proof: TemplateModule ConvictionLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
 	/// Storage: TemplateModule MaxVote (r:1 w:1)
-	/// Proof: TemplateModule MaxVote (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Proof: TemplateModule MaxVote (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VotedCount (r:1 w:1)
+	/// Proof: TemplateModule VotedCount (max_values: None, max_size: Some(12), added: 2487, mode: MaxEncodedLen)
 	fn give_vote() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `185`
-		//  Estimated: `10048`
-		// Minimum execution time: 26_976_000 picoseconds.
-		Weight::from_parts(27_948_000, 0)
-			.saturating_add(Weight::from_parts(0, 10048))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		//  Measured:  `19874`
+		//  Estimated: `332418`
+		// Minimum execution time: 471_239_000 picoseconds.
+		Weight::from_parts(478_902_000, 0)
+			.saturating_add(Weight::from_parts(0, 332418))
+			.saturating_add(T::DbWeight::get().reads(119))
+			.saturating_add(T::DbWeight::get().writes(15))
 	}
 	/// Storage: TemplateModule ElectionConfig (r:1 w:0)
-	/// Proof: TemplateModule ElectionConfig (max_values: Some(1), max_size: Some(10), added: 505, mode: MaxEncodedLen)
-	/// Storage: TemplateModule AccountToCandidateInfo (r:101 w:0)
-	/// Proof: TemplateModule AccountToCandidateInfo (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Proof: TemplateModule ElectionConfig (max_values: None, max_size: Some(120), added: 2595, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ClosedElections (r:1 w:1)
+	/// Proof: TemplateModule ClosedElections (max_values: None, max_size: Some(17), added: 2492, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VotedCount (r:1 w:0)
+	/// Proof: TemplateModule VotedCount (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AccountToCandidateInfo (r:301 w:100)
+	/// Proof: TemplateModule AccountToCandidateInfo (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
 	/// Storage: TemplateModule MaxVote (r:1 w:0)
-	/// Proof: TemplateModule MaxVote (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Proof: TemplateModule MaxVote (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
 	/// Storage: TemplateModule MaxVoteCandidate (r:0 w:1)
-	/// Proof: TemplateModule MaxVoteCandidate (max_values: Some(1), max_size: Some(3202), added: 3697, mode: MaxEncodedLen)
-	fn winner() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `5887`
-		//  Estimated: `259201`
-		// Minimum execution time: 498_291_000 picoseconds.
-		Weight::from_parts(512_186_000, 0)
-			.saturating_add(Weight::from_parts(0, 259201))
+	/// Proof: TemplateModule MaxVoteCandidate (max_values: None, max_size: Some(3214), added: 5689, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ElectionResults (r:0 w:1)
+	/// Proof: TemplateModule ElectionResults (max_values: None, max_size: Some(6486), added: 8961, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PendingPurges (r:0 w:1)
+	/// Proof: TemplateModule PendingPurges (max_values: None, max_size: Some(270), added: 2745, mode: MaxEncodedLen)
+	/// The range of component `c` is `[1, 100]`.
+	fn winner(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `418 + c * (310 ±0)`
+		//  Estimated: `24692 + c * (8355 ±0)`
+		// Minimum execution time: 61_408_000 picoseconds.
+		Weight::from_parts(63_927_000, 0)
+			.saturating_add(Weight::from_parts(0, 24692))
+			// Standard Error: 38_114
+			.saturating_add(Weight::from_parts(14_283_506, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 8355).saturating_mul(c.into()))
+	}
+	/// Storage: TemplateModule ElectionConfig (r:1 w:0)
+	/// Proof: TemplateModule ElectionConfig (max_values: None, max_size: Some(120), added: 2595, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ClosedElections (r:1 w:1)
+	/// Proof: TemplateModule ClosedElections (max_values: None, max_size: Some(17), added: 2492, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VotedCount (r:1 w:0)
+	/// Proof: TemplateModule VotedCount (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AccountToCandidateInfo (r:101 w:100)
+	/// Proof: TemplateModule AccountToCandidateInfo (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: TemplateModule RankedBallots (r:1001 w:0)
+	/// Proof: TemplateModule RankedBallots (max_values: None, max_size: Some(3259), added: 5734, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PairwisePreferences (r:0 w:9900)
+	/// Proof: TemplateModule PairwisePreferences (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: TemplateModule CondorcetWinner (r:0 w:1)
+	/// Proof: TemplateModule CondorcetWinner (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: TemplateModule MaxVoteCandidate (r:0 w:1)
+	/// Proof: TemplateModule MaxVoteCandidate (max_values: None, max_size: Some(3214), added: 5689, mode: MaxEncodedLen)
+	/// The range of component `c` is `[2, 100]`.
+	/// The range of component `b` is `[1, 1000]`.
+	fn winner_ranked(c: u32, b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512 + b * (3259 ±0) + c * (310 ±0)`
+		//  Estimated: `21390 + b * (5734 ±0) + c * (2785 ±0)`
+		// Minimum execution time: 1_904_118_000 picoseconds.
+		Weight::from_parts(1_904_118_000, 0)
+			.saturating_add(Weight::from_parts(0, 21390))
+			// Standard Error: 412_906
+			.saturating_add(Weight::from_parts(96_317_412, 0).saturating_mul(c.into()))
+			// Standard Error: 40_877
+			.saturating_add(Weight::from_parts(9_842_551, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((100_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2785).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(0, 5734).saturating_mul(b.into()))
+	}
+	/// Storage: TemplateModule ElectionConfig (r:1 w:0)
+	/// Proof: TemplateModule ElectionConfig (max_values: None, max_size: Some(120), added: 2595, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ClosedElections (r:1 w:1)
+	/// Proof: TemplateModule ClosedElections (max_values: None, max_size: Some(17), added: 2492, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VotedCount (r:1 w:0)
+	/// Proof: TemplateModule VotedCount (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AccountToCandidateInfo (r:101 w:100)
+	/// Proof: TemplateModule AccountToCandidateInfo (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: TemplateModule RankedBallots (r:2002 w:0)
+	/// Proof: TemplateModule RankedBallots (max_values: None, max_size: Some(3259), added: 5734, mode: MaxEncodedLen)
+	/// Storage: TemplateModule StvRounds (r:0 w:99)
+	/// Proof: TemplateModule StvRounds (max_values: None, max_size: Some(4067), added: 6542, mode: MaxEncodedLen)
+	/// Storage: TemplateModule MaxVoteCandidate (r:0 w:1)
+	/// Proof: TemplateModule MaxVoteCandidate (max_values: None, max_size: Some(3214), added: 5689, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ElectionResults (r:0 w:1)
+	/// Proof: TemplateModule ElectionResults (max_values: None, max_size: Some(6486), added: 8961, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PendingPurges (r:0 w:1)
+	/// Proof: TemplateModule PendingPurges (max_values: None, max_size: Some(270), added: 2745, mode: MaxEncodedLen)
+	/// The range of component `c` is `[2, 100]`.
+	/// The range of component `b` is `[1, 1000]`.
+	fn winner_stv(c: u32, b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `531 + b * (3259 ±0) + c * (310 ±0)`
+		//  Estimated: `27208 + b * (11468 ±0) + c * (9327 ±0)`
+		// Minimum execution time: 2_716_530_000 picoseconds.
+		Weight::from_parts(2_716_530_000, 0)
+			.saturating_add(Weight::from_parts(0, 27208))
+			// Standard Error: 651_220
+			.saturating_add(Weight::from_parts(147_906_118, 0).saturating_mul(c.into()))
+			// Standard Error: 64_493
+			.saturating_add(Weight::from_parts(15_228_704, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 9327).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(0, 11468).saturating_mul(b.into()))
+	}
+	/// Storage: TemplateModule ElectionConfig (r:2 w:0)
+	/// Proof: TemplateModule ElectionConfig (max_values: None, max_size: Some(27), added: 2502, mode: MaxEncodedLen)
+	/// Storage: TemplateModule RegisteredVoters (r:1 w:0)
+	/// Proof: TemplateModule RegisteredVoters (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AccountToVoterInfo (r:1 w:1)
+	/// Proof: TemplateModule AccountToVoterInfo (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
+	/// Storage: TemplateModule UnrevealedVotes (r:1 w:1)
+	/// Proof: TemplateModule UnrevealedVotes (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ConvictionLocks (r:1 w:1)
+	/// Proof: TemplateModule ConvictionLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	fn commit_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `531`
+		//  Estimated: `21539`
+		// Minimum execution time: 55_906_000 picoseconds.
+		Weight::from_parts(57_281_000, 0)
+			.saturating_add(Weight::from_parts(0, 21539))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: TemplateModule ElectionConfig (r:1 w:0)
+	/// Proof: TemplateModule ElectionConfig (max_values: None, max_size: Some(27), added: 2502, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AccountToVoterInfo (r:1 w:1)
+	/// Proof: TemplateModule AccountToVoterInfo (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AccountToCandidateInfo (r:2 w:1)
+	/// Proof: TemplateModule AccountToCandidateInfo (max_values: None, max_size: Some(293), added: 2768, mode: MaxEncodedLen)
+	/// Storage: TemplateModule UnrevealedVotes (r:1 w:1)
+	/// Proof: TemplateModule UnrevealedVotes (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: TemplateModule MaxVote (r:1 w:1)
+	/// Proof: TemplateModule MaxVote (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	fn reveal_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `498`
+		//  Estimated: `15118`
+		// Minimum execution time: 38_640_000 picoseconds.
+		Weight::from_parts(39_871_000, 0)
+			.saturating_add(Weight::from_parts(0, 15118))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: TemplateModule ElectionConfig (r:1 w:0)
+	/// Proof: TemplateModule ElectionConfig (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule RegisteredVoters (r:1 w:0)
+	/// Proof: TemplateModule RegisteredVoters (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AccountToVoterInfo (r:1 w:1)
+	/// Proof: TemplateModule AccountToVoterInfo (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AccountToCandidateInfo (r:102 w:1)
+	/// Proof: TemplateModule AccountToCandidateInfo (max_values: None, max_size: Some(293), added: 2768, mode: MaxEncodedLen)
+	/// Storage: TemplateModule MaxVote (r:1 w:1)
+	/// Proof: TemplateModule MaxVote (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: TemplateModule RankedBallots (r:0 w:1)
+	/// Proof: TemplateModule RankedBallots (max_values: None, max_size: Some(3262), added: 5737, mode: MaxEncodedLen)
+	fn give_ranked_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `26411`
+		//  Estimated: `292153`
+		// Minimum execution time: 412_806_000 picoseconds.
+		Weight::from_parts(421_337_000, 0)
+			.saturating_add(Weight::from_parts(0, 292153))
+			.saturating_add(T::DbWeight::get().reads(106))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: TemplateModule ElectionConfig (r:1 w:0)
+	/// Proof: TemplateModule ElectionConfig (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule RegisteredVoters (r:1 w:0)
+	/// Proof: TemplateModule RegisteredVoters (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AccountToVoterInfo (r:1 w:1)
+	/// Proof: TemplateModule AccountToVoterInfo (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AccountToCandidateInfo (r:300 w:100)
+	/// Proof: TemplateModule AccountToCandidateInfo (max_values: None, max_size: Some(293), added: 2768, mode: MaxEncodedLen)
+	/// Storage: TemplateModule MaxVote (r:100 w:1)
+	/// Proof: TemplateModule MaxVote (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ApprovalBallots (r:0 w:1)
+	/// Proof: TemplateModule ApprovalBallots (max_values: None, max_size: Some(3262), added: 5737, mode: MaxEncodedLen)
+	fn give_approval_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `26411`
+		//  Estimated: `1088853`
+		// Minimum execution time: 1_604_227_000 picoseconds.
+		Weight::from_parts(1_631_940_000, 0)
+			.saturating_add(Weight::from_parts(0, 1088853))
+			.saturating_add(T::DbWeight::get().reads(403))
+			.saturating_add(T::DbWeight::get().writes(103))
+	}
+	/// Storage: TemplateModule ElectionConfig (r:1 w:0)
+	/// Proof: TemplateModule ElectionConfig (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: TemplateModule RegisteredVoters (r:1 w:0)
+	/// Proof: TemplateModule RegisteredVoters (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AccountToVoterInfo (r:1 w:1)
+	/// Proof: TemplateModule AccountToVoterInfo (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AccountToCandidateInfo (r:300 w:100)
+	/// Proof: TemplateModule AccountToCandidateInfo (max_values: None, max_size: Some(293), added: 2768, mode: MaxEncodedLen)
+	/// Storage: TemplateModule MaxVote (r:100 w:1)
+	/// Proof: TemplateModule MaxVote (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ScoreBallots (r:0 w:1)
+	/// Proof: TemplateModule ScoreBallots (max_values: None, max_size: Some(3662), added: 6137, mode: MaxEncodedLen)
+	fn give_score_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `26411`
+		//  Estimated: `1089253`
+		// Minimum execution time: 1_622_508_000 picoseconds.
+		Weight::from_parts(1_650_174_000, 0)
+			.saturating_add(Weight::from_parts(0, 1089253))
+			.saturating_add(T::DbWeight::get().reads(403))
+			.saturating_add(T::DbWeight::get().writes(103))
+	}
+	/// Storage: TemplateModule ElectionConfig (r:1 w:0)
+	/// Proof: TemplateModule ElectionConfig (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule RegisteredVoters (r:1 w:0)
+	/// Proof: TemplateModule RegisteredVoters (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AccountToCandidateInfo (r:3 w:1)
+	/// Proof: TemplateModule AccountToCandidateInfo (max_values: None, max_size: Some(293), added: 2768, mode: MaxEncodedLen)
+	/// Storage: TemplateModule QuadraticBallots (r:1 w:1)
+	/// Proof: TemplateModule QuadraticBallots (max_values: None, max_size: Some(3662), added: 6137, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AccountToVoterInfo (r:1 w:1)
+	/// Proof: TemplateModule AccountToVoterInfo (max_values: None, max_size: Some(127), added: 2602, mode: MaxEncodedLen)
+	/// Storage: TemplateModule MaxVote (r:1 w:1)
+	/// Proof: TemplateModule MaxVote (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	fn give_votes() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4180`
+		//  Estimated: `25327`
+		// Minimum execution time: 58_311_000 picoseconds.
+		Weight::from_parts(60_024_000, 0)
+			.saturating_add(Weight::from_parts(0, 25327))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: TemplateModule ElectionConfig (r:1 w:0)
+	/// Proof: TemplateModule ElectionConfig (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
+	/// Storage: TemplateModule RegisteredVoters (r:1 w:0)
+	/// Proof: TemplateModule RegisteredVoters (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AccountToVoterInfo (r:10 w:9)
+	/// Proof: TemplateModule AccountToVoterInfo (max_values: None, max_size: Some(143), added: 2618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AccountToCandidateInfo (r:102 w:2)
+	/// Proof: TemplateModule AccountToCandidateInfo (max_values: None, max_size: Some(305), added: 2780, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ConvictionLocks (r:1 w:1)
+	/// Proof: TemplateModule ConvictionLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: TemplateModule MaxVote (r:1 w:1)
+	/// Proof: TemplateModule MaxVote (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VotedCount (r:1 w:1)
+	/// Proof: TemplateModule VotedCount (max_values: None, max_size: Some(12), added: 2487, mode: MaxEncodedLen)
+	fn give_conviction_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `19874`
+		//  Estimated: `332418`
+		// Minimum execution time: 482_116_000 picoseconds.
+		Weight::from_parts(490_357_000, 0)
+			.saturating_add(Weight::from_parts(0, 332418))
+			.saturating_add(T::DbWeight::get().reads(119))
+			.saturating_add(T::DbWeight::get().writes(15))
+	}
+	/// Storage: TemplateModule ConvictionLocks (r:2 w:1)
+	/// Proof: TemplateModule ConvictionLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ElectionConfig (r:1 w:0)
+	/// Proof: TemplateModule ElectionConfig (max_values: None, max_size: Some(120), added: 2595, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn unlock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `592`
+		//  Estimated: `14082`
+		// Minimum execution time: 43_118_000 picoseconds.
+		Weight::from_parts(44_602_000, 0)
+			.saturating_add(Weight::from_parts(0, 14082))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: TemplateModule ElectionConfig (r:1 w:0)
+	/// Proof: TemplateModule ElectionConfig (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
+	/// Storage: TemplateModule RegisteredVoters (r:2 w:0)
+	/// Proof: TemplateModule RegisteredVoters (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AccountToVoterInfo (r:18 w:9)
+	/// Proof: TemplateModule AccountToVoterInfo (max_values: None, max_size: Some(143), added: 2618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AccountToCandidateInfo (r:2 w:1)
+	/// Proof: TemplateModule AccountToCandidateInfo (max_values: None, max_size: Some(305), added: 2780, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ConvictionLocks (r:1 w:1)
+	/// Proof: TemplateModule ConvictionLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: TemplateModule MaxVote (r:1 w:1)
+	/// Proof: TemplateModule MaxVote (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `19760`
+		//  Estimated: `75634`
+		// Minimum execution time: 162_804_000 picoseconds.
+		Weight::from_parts(166_271_000, 0)
+			.saturating_add(Weight::from_parts(0, 75634))
+			.saturating_add(T::DbWeight::get().reads(27))
+			.saturating_add(T::DbWeight::get().writes(13))
+	}
+	/// Storage: TemplateModule ElectionConfig (r:1 w:0)
+	/// Proof: TemplateModule ElectionConfig (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AccountToVoterInfo (r:18 w:9)
+	/// Proof: TemplateModule AccountToVoterInfo (max_values: None, max_size: Some(143), added: 2618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AccountToCandidateInfo (r:101 w:1)
+	/// Proof: TemplateModule AccountToCandidateInfo (max_values: None, max_size: Some(305), added: 2780, mode: MaxEncodedLen)
+	/// Storage: TemplateModule MaxVote (r:0 w:1)
+	/// Proof: TemplateModule MaxVote (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn undelegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `19760`
+		//  Estimated: `330421`
+		// Minimum execution time: 448_573_000 picoseconds.
+		Weight::from_parts(455_018_000, 0)
+			.saturating_add(Weight::from_parts(0, 330421))
+			.saturating_add(T::DbWeight::get().reads(120))
+			.saturating_add(T::DbWeight::get().writes(11))
+	}
+	/// Storage: TemplateModule ElectionConfig (r:1 w:0)
+	/// Proof: TemplateModule ElectionConfig (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AccountToVoterInfo (r:1 w:1)
+	/// Proof: TemplateModule AccountToVoterInfo (max_values: None, max_size: Some(143), added: 2618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AccountToCandidateInfo (r:102 w:2)
+	/// Proof: TemplateModule AccountToCandidateInfo (max_values: None, max_size: Some(305), added: 2780, mode: MaxEncodedLen)
+	/// Storage: TemplateModule MaxVote (r:1 w:1)
+	/// Proof: TemplateModule MaxVote (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn change_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `17642`
+		//  Estimated: `292596`
+		// Minimum execution time: 412_508_000 picoseconds.
+		Weight::from_parts(421_933_000, 0)
+			.saturating_add(Weight::from_parts(0, 292596))
+			.saturating_add(T::DbWeight::get().reads(105))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: TemplateModule ElectionConfig (r:1 w:0)
+	/// Proof: TemplateModule ElectionConfig (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AccountToVoterInfo (r:1 w:1)
+	/// Proof: TemplateModule AccountToVoterInfo (max_values: None, max_size: Some(143), added: 2618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AccountToCandidateInfo (r:101 w:1)
+	/// Proof: TemplateModule AccountToCandidateInfo (max_values: None, max_size: Some(305), added: 2780, mode: MaxEncodedLen)
+	/// Storage: TemplateModule MaxVote (r:0 w:1)
+	/// Proof: TemplateModule MaxVote (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn revoke_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `17642`
+		//  Estimated: `289816`
+		// Minimum execution time: 398_114_000 picoseconds.
+		Weight::from_parts(405_672_000, 0)
+			.saturating_add(Weight::from_parts(0, 289816))
 			.saturating_add(T::DbWeight::get().reads(103))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: TemplateModule VoterRoll (r:1000 w:1000)
+	/// Proof: TemplateModule VoterRoll (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule RegisteredVoters (r:1000 w:0)
+	/// Proof: TemplateModule RegisteredVoters (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VoterRollCount (r:1 w:1)
+	/// Proof: TemplateModule VoterRollCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule OpenElectorateCount (r:1 w:1)
+	/// Proof: TemplateModule OpenElectorateCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// The range of component `v` is `[1, 1000]`.
+	fn add_voters(v: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `998`
+		// Minimum execution time: 13_482_000 picoseconds.
+		Weight::from_parts(13_790_000, 0)
+			.saturating_add(Weight::from_parts(0, 998))
+			// Standard Error: 2_113
+			.saturating_add(Weight::from_parts(8_204_517, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 5046).saturating_mul(v.into()))
+	}
+	/// Storage: TemplateModule VoterRoll (r:1 w:1)
+	/// Proof: TemplateModule VoterRoll (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VoterRollCount (r:1 w:1)
+	/// Proof: TemplateModule VoterRollCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule RegisteredVoters (r:1 w:0)
+	/// Proof: TemplateModule RegisteredVoters (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule OpenElectorateCount (r:1 w:1)
+	/// Proof: TemplateModule OpenElectorateCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn remove_voter() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `119`
+		//  Estimated: `6044`
+		// Minimum execution time: 19_406_000 picoseconds.
+		Weight::from_parts(20_011_000, 0)
+			.saturating_add(Weight::from_parts(0, 6044))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: TemplateModule ElectionConfig (r:1 w:0)
+	/// Proof: TemplateModule ElectionConfig (max_values: None, max_size: Some(82), added: 2557, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VoterRoll (r:1 w:0)
+	/// Proof: TemplateModule VoterRoll (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule RegisteredVoters (r:1 w:0)
+	/// Proof: TemplateModule RegisteredVoters (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule EnrolledVoters (r:1 w:1)
+	/// Proof: TemplateModule EnrolledVoters (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule EnrolledVoterCount (r:1 w:1)
+	/// Proof: TemplateModule EnrolledVoterCount (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	fn enroll() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `231`
+		//  Estimated: `16617`
+		// Minimum execution time: 24_388_000 picoseconds.
+		Weight::from_parts(25_102_000, 0)
+			.saturating_add(Weight::from_parts(0, 16617))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: TemplateModule ElectionConfig (r:1 w:0)
+	/// Proof: TemplateModule ElectionConfig (max_values: None, max_size: Some(120), added: 2595, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ClosedElections (r:1 w:1)
+	/// Proof: TemplateModule ClosedElections (max_values: None, max_size: Some(17), added: 2492, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ElectionsToFinalize (r:1 w:1)
+	/// Proof: TemplateModule ElectionsToFinalize (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AccountToCandidateInfo (r:101 w:100)
+	/// Proof: TemplateModule AccountToCandidateInfo (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: System Account (r:100 w:100)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn cancel_election() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `40511`
+		//  Estimated: `547919`
+		// Minimum execution time: 1_482_336_000 picoseconds.
+		Weight::from_parts(1_507_914_000, 0)
+			.saturating_add(Weight::from_parts(0, 547919))
+			.saturating_add(T::DbWeight::get().reads(204))
+			.saturating_add(T::DbWeight::get().writes(202))
+	}
+	/// Storage: TemplateModule ElectionConfig (r:1 w:1)
+	/// Proof: TemplateModule ElectionConfig (max_values: None, max_size: Some(120), added: 2595, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ClosedElections (r:1 w:0)
+	/// Proof: TemplateModule ClosedElections (max_values: None, max_size: Some(17), added: 2492, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ElectionsToFinalize (r:2 w:2)
+	/// Proof: TemplateModule ElectionsToFinalize (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	fn extend_voting() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `187`
+		//  Estimated: `13221`
+		// Minimum execution time: 27_915_000 picoseconds.
+		Weight::from_parts(28_660_000, 0)
+			.saturating_add(Weight::from_parts(0, 13221))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: TemplateModule ElectionConfig (r:1 w:1)
+	/// Proof: TemplateModule ElectionConfig (max_values: None, max_size: Some(120), added: 2595, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ClosedElections (r:1 w:0)
+	/// Proof: TemplateModule ClosedElections (max_values: None, max_size: Some(17), added: 2492, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ElectionsToFinalize (r:2 w:2)
+	/// Proof: TemplateModule ElectionsToFinalize (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	fn postpone() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `187`
+		//  Estimated: `13221`
+		// Minimum execution time: 28_104_000 picoseconds.
+		Weight::from_parts(28_892_000, 0)
+			.saturating_add(Weight::from_parts(0, 13221))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: TemplateModule ClosedElections (r:1 w:0)
+	/// Proof: TemplateModule ClosedElections (max_values: None, max_size: Some(17), added: 2492, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PendingPurges (r:1 w:1)
+	/// Proof: TemplateModule PendingPurges (max_values: None, max_size: Some(272), added: 2747, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AccountToVoterInfo (r:0 w:1000)
+	/// Proof: TemplateModule AccountToVoterInfo (max_values: None, max_size: Some(197), added: 2672, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AccountToCandidateInfo (r:0 w:100)
+	/// Proof: TemplateModule AccountToCandidateInfo (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: TemplateModule CandidateCount (r:0 w:1)
+	/// Proof: TemplateModule CandidateCount (max_values: None, max_size: Some(12), added: 2487, mode: MaxEncodedLen)
+	/// Storage: TemplateModule MaxVote (r:0 w:1)
+	/// Proof: TemplateModule MaxVote (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VotedCount (r:0 w:1)
+	/// Proof: TemplateModule VotedCount (max_values: None, max_size: Some(12), added: 2487, mode: MaxEncodedLen)
+	/// Storage: TemplateModule EnrolledVoterCount (r:0 w:1)
+	/// Proof: TemplateModule EnrolledVoterCount (max_values: None, max_size: Some(12), added: 2487, mode: MaxEncodedLen)
+	/// Storage: TemplateModule UnrevealedVotes (r:0 w:1)
+	/// Proof: TemplateModule UnrevealedVotes (max_values: None, max_size: Some(12), added: 2487, mode: MaxEncodedLen)
+	/// Storage: TemplateModule MaxVoteCandidate (r:0 w:1)
+	/// Proof: TemplateModule MaxVoteCandidate (max_values: None, max_size: Some(3214), added: 5689, mode: MaxEncodedLen)
+	/// Storage: TemplateModule CondorcetWinner (r:0 w:1)
+	/// Proof: TemplateModule CondorcetWinner (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: TemplateModule StarFinalists (r:0 w:1)
+	/// Proof: TemplateModule StarFinalists (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TieBreaks (r:0 w:1)
+	/// Proof: TemplateModule TieBreaks (max_values: None, max_size: Some(3253), added: 5728, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 1100]`.
+	fn purge_election(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `208 + n * (87 ±0)`
+		//  Estimated: `10478 + n * (87 ±0)`
+		// Minimum execution time: 36_812_000 picoseconds.
+		Weight::from_parts(37_406_000, 0)
+			.saturating_add(Weight::from_parts(0, 10478))
+			// Standard Error: 1_302
+			.saturating_add(Weight::from_parts(1_486_233, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(10))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 87).saturating_mul(n.into()))
 	}
 }
@@ -263,10 +263,39 @@ impl pallet_sudo::Config for Runtime {
 	type RuntimeCall = RuntimeCall;
 }
 
+parameter_types! {
+	pub const CandidacyBondThreshold: Perbill = Perbill::from_percent(5);
+	pub MaxFinalizationWeight: Weight = Perbill::from_percent(10) * BlockWeights::get().max_block;
+}
+
+/// Randomness drawn from the parent block hash. A block author can predict and sway it, so it
+/// only suits low-stakes draws such as breaking a tied election.
+pub struct ParentHashRandomness;
+
+impl Randomness<Hash, BlockNumber> for ParentHashRandomness {
+	fn random(subject: &[u8]) -> (Hash, BlockNumber) {
+		let seed =
+			<BlakeTwo256 as sp_runtime::traits::Hash>::hash_of(&(subject, System::parent_hash()));
+		(seed, System::block_number().saturating_sub(1))
+	}
+}
+
 /// Configure the pallet-template in pallets/template.
 impl pallet_template::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_template::TemplateWeightInfo<Runtime>;
+	type TallyMethods = pallet_template::BuiltinTallies<Runtime>;
+	type Currency = Balances;
+	type ConvictionPeriod = ConstU32<{ 7 * DAYS }>;
+	type MaxDelegationDepth = ConstU32<8>;
+	type ElectionAuthority = frame_system::EnsureRoot<AccountId>;
+	type CandidacyBond = ConstU128<{ 100 * EXISTENTIAL_DEPOSIT }>;
+	type CandidacyBondThreshold = CandidacyBondThreshold;
+	// No treasury in this runtime, so forfeited bonds are burned
+	type ForfeitedBond = ();
+	type MaxElectionsPerBlock = ConstU32<10>;
+	type MaxFinalizationWeight = MaxFinalizationWeight;
+	type Randomness = ParentHashRandomness;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.